@@ -24,8 +24,8 @@ flex_error::define_error! {
 
         Divergence
             {
-                evidence: GatheredEvidence,
-                challenging_block: LightBlock,
+                evidence: Box<GatheredEvidence>,
+                challenging_block: Box<LightBlock>,
             }
             |e| { format_args!("divergence detected, found evidence: {:#?}", e.evidence) },
 
@@ -82,6 +82,18 @@ flex_error::define_error! {
                 )
             },
 
+        ConflictingHeightMismatch
+            {
+                trusted_height: Height,
+                conflicting_height: Height,
+            }
+            |e| {
+                format_args!(
+                    "conflicting block height ({}) differs from trusted block height ({})",
+                    e.conflicting_height, e.trusted_height
+                )
+            },
+
         FailedHeaderCrossReferencing
             |_| { format_args!("failed to cross-reference header with witness") },
     }
@@ -2,12 +2,51 @@ use std::cmp::Ordering;
 
 use tendermint::{
     block::{signed_header::SignedHeader, Header},
-    evidence::{ConflictingBlock, LightClientAttackEvidence},
+    evidence::{ConflictingBlock, Evidence, LightClientAttackEvidence},
     validator,
 };
 
 use tendermint_light_client::verifier::types::LightBlock;
 
+use crate::error::Error;
+
+/// Forms [`LightClientAttackEvidence`] out of the conflicting headers found by
+/// the detector.
+pub trait LightClientAttackEvidenceExt {
+    /// Forms the evidence of a light client attack out of a `conflicting` light block
+    /// and the `trusted` light block at the same height it conflicts with, e.g. as found
+    /// by [`detect_divergence`](crate::detect_divergence).
+    ///
+    /// The `trusted` light block also stands for the latest block both chains agree on:
+    /// in case of a lunatic attack, its validators are the ones accused of signing the
+    /// conflicting header and its height is the common height of the evidence.
+    fn from_conflicting_headers(
+        trusted: &LightBlock,
+        conflicting: &LightBlock,
+    ) -> Result<Evidence, Error>;
+}
+
+impl LightClientAttackEvidenceExt for LightClientAttackEvidence {
+    fn from_conflicting_headers(
+        trusted: &LightBlock,
+        conflicting: &LightBlock,
+    ) -> Result<Evidence, Error> {
+        if trusted.height() != conflicting.height() {
+            return Err(Error::conflicting_height_mismatch(
+                trusted.height(),
+                conflicting.height(),
+            ));
+        }
+
+        if trusted.signed_header.header.hash() == conflicting.signed_header.header.hash() {
+            return Err(Error::no_divergence());
+        }
+
+        let evidence = make_evidence(conflicting.clone(), trusted.clone(), trusted.clone());
+        Ok(Evidence::from(evidence))
+    }
+}
+
 /// Determines the type of attack and then forms the evidence filling out
 /// all the fields such that it is ready to be sent to a full node.
 pub fn make_evidence(
@@ -99,7 +138,7 @@ fn find_lunatic_validators(
 
         let validator = commit_sig
             .validator_address()
-            .and_then(|addr| common_validators.validator(addr));
+            .and_then(|addr| common_validators.validator(&addr).cloned());
 
         if let Some(validator) = validator {
             validators.push(validator);
@@ -134,7 +173,7 @@ fn find_equivocating_validators(
 
         let validator = sig_a
             .validator_address()
-            .and_then(|addr| conflicted.validator_set.validator(addr));
+            .and_then(|addr| conflicted.validator_set.validator(&addr).cloned());
 
         if let Some(validator) = validator {
             validators.push(validator);
@@ -150,3 +189,80 @@ fn cmp_voting_power_then_address(a: &validator::Info, b: &validator::Info) -> Or
         .cmp(&b.power)
         .then_with(|| a.address.cmp(&b.address))
 }
+
+#[cfg(test)]
+mod tests {
+    use tendermint_rpc::{dialect::v0_37::Dialect, endpoint::evidence};
+    use tendermint_testgen::{
+        light_block::generate_fork, Generator, LightBlock as TestgenLightBlock,
+    };
+
+    use super::*;
+
+    fn light_block(lb: TestgenLightBlock) -> LightBlock {
+        let lb = lb.generate().unwrap();
+        LightBlock::new(
+            lb.signed_header,
+            lb.validators,
+            lb.next_validators,
+            lb.provider,
+        )
+    }
+
+    #[test]
+    fn evidence_from_forked_block_serializes_for_broadcast() {
+        let (block, forked_block) = generate_fork(&TestgenLightBlock::new_default(1), 3_u32.into());
+        let trusted = light_block(block);
+        let conflicting = light_block(forked_block);
+
+        let evidence =
+            LightClientAttackEvidence::from_conflicting_headers(&trusted, &conflicting).unwrap();
+        let Evidence::LightClientAttack(attack) = &evidence else {
+            panic!("unexpected evidence: {evidence:?}");
+        };
+        assert_eq!(attack.common_height, trusted.height());
+        assert_eq!(
+            attack.conflicting_block.signed_header,
+            conflicting.signed_header
+        );
+        // Every trusted validator signed the lunatic header
+        assert_eq!(
+            attack.byzantine_validators.len(),
+            trusted.validators.validators().len()
+        );
+
+        let request = evidence::Request::<Dialect>::new(evidence);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["evidence"]["type"],
+            "tendermint/LightClientAttackEvidence"
+        );
+        let attack = &json["evidence"]["value"];
+        assert_eq!(attack["CommonHeight"], "3");
+        assert_eq!(
+            attack["ConflictingBlock"]["signed_header"]["header"]["height"],
+            "3"
+        );
+        assert!(attack["ConflictingBlock"]["validator_set"]["validators"].is_array());
+        assert_eq!(attack["ByzantineValidators"].as_array().unwrap().len(), 2);
+        assert!(attack["TotalVotingPower"].is_string());
+        assert!(attack["Timestamp"].is_string());
+    }
+
+    #[test]
+    fn evidence_requires_conflicting_headers_at_the_same_height() {
+        let (block, forked_block) = generate_fork(&TestgenLightBlock::new_default(1), 3_u32.into());
+        let trusted = light_block(block);
+
+        let err =
+            LightClientAttackEvidence::from_conflicting_headers(&trusted, &trusted).unwrap_err();
+        assert!(matches!(err.detail(), crate::ErrorDetail::NoDivergence(_)));
+
+        let next = light_block(TestgenLightBlock::next(&forked_block));
+        let err = LightClientAttackEvidence::from_conflicting_headers(&trusted, &next).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            crate::ErrorDetail::ConflictingHeightMismatch(_)
+        ));
+    }
+}
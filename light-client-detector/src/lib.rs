@@ -13,6 +13,7 @@ mod trace;
 pub use conflict::gather_evidence_from_conflicting_headers;
 pub use detect::{compare_new_header_with_witness, detect_divergence, CompareError, Divergence};
 pub use error::{Error, ErrorDetail};
+pub use evidence::LightClientAttackEvidenceExt;
 pub use provider::Provider;
 pub use tendermint::evidence::{Evidence, LightClientAttackEvidence};
 pub use trace::Trace;
@@ -0,0 +1,107 @@
+//! Compares the verification of an adjacent header carrying the trusted next
+//! validator set, which skips re-hashing that validator set, to the general
+//! verification path.
+//!
+//! Run with `cargo bench -p tendermint-light-client-verifier --bench verify_adjacent`.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use tendermint::Time;
+use tendermint_light_client_verifier::{
+    options::Options,
+    types::{LightBlock, TrustedBlockState, UntrustedBlockState},
+    ProdVerifier, Verdict, Verifier,
+};
+use tendermint_testgen::{
+    light_block::{LightBlock as TestgenLightBlock, TmLightBlock},
+    Generator, Header, Validator,
+};
+
+const VALIDATORS: usize = 100;
+
+fn testgen_to_lb(tm_lb: TmLightBlock) -> LightBlock {
+    LightBlock {
+        signed_header: tm_lb.signed_header,
+        validators: tm_lb.validators,
+        next_validators: tm_lb.next_validators,
+        provider: tm_lb.provider,
+    }
+}
+
+/// Runs every check of `verify_update_header` without the adjacent-block fast path.
+fn verify_update_header_general(
+    vp: &ProdVerifier,
+    untrusted: UntrustedBlockState<'_>,
+    trusted: TrustedBlockState<'_>,
+    options: &Options,
+    now: Time,
+) -> Verdict {
+    let checks: [&dyn Fn() -> Verdict; 4] = [
+        &|| vp.verify_validator_sets(&untrusted),
+        &|| vp.validate_against_trusted(&untrusted, &trusted, options, now),
+        &|| vp.check_header_is_from_past(&untrusted, options, now),
+        &|| vp.verify_commit_against_trusted(&untrusted, &trusted, options),
+    ];
+    checks
+        .iter()
+        .map(|check| check())
+        .find(|verdict| *verdict != Verdict::Success)
+        .unwrap_or(Verdict::Success)
+}
+
+fn verify_adjacent(c: &mut Criterion) {
+    let validators = (0..VALIDATORS)
+        .map(|i| Validator::new(&i.to_string()).voting_power(50))
+        .collect::<Vec<_>>();
+    let header = Header::new(&validators)
+        .height(1)
+        .chain_id("bench-chain")
+        .next_validators(&validators)
+        .time(Time::from_unix_timestamp(1, 0).unwrap());
+
+    let testgen_block = TestgenLightBlock::new_default_with_header(header);
+    let trusted = testgen_to_lb(testgen_block.generate().unwrap());
+    let untrusted = testgen_to_lb(testgen_block.next().generate().unwrap());
+
+    let vp = ProdVerifier::default();
+    let options = Options {
+        trust_threshold: Default::default(),
+        trusting_period: Duration::from_secs(3600),
+        clock_drift: Default::default(),
+    };
+    let now = Time::from_unix_timestamp(10, 0).unwrap();
+
+    let mut group = c.benchmark_group("verify_adjacent");
+    group.bench_function("general", |b| {
+        b.iter(|| {
+            assert_eq!(
+                verify_update_header_general(
+                    &vp,
+                    untrusted.as_untrusted_state(),
+                    trusted.as_trusted_state(),
+                    &options,
+                    now,
+                ),
+                Verdict::Success
+            )
+        })
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| {
+            assert_eq!(
+                vp.verify_update_header(
+                    untrusted.as_untrusted_state(),
+                    trusted.as_trusted_state(),
+                    &options,
+                    now,
+                ),
+                Verdict::Success
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, verify_adjacent);
+criterion_main!(benches);
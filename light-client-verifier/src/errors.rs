@@ -69,8 +69,18 @@ define_error! {
                 sign_bytes: Vec<u8>,
             }
             | e | {
-                format_args!("failed to verify signature `{:?}` with validator `{:?}` on sign_bytes `{:?}`",
-                    e.signature, e.validator, e.sign_bytes)
+                format_args!("failed to verify signature `{:?}` of validator {} (`{:?}`) on sign_bytes `{:?}`",
+                    e.signature, e.validator.address, e.validator, e.sign_bytes)
+            },
+
+        TimestampPrecisionLost
+            {
+                validator_address: ValidatorAddress,
+                timestamp: Time,
+            }
+            | e | {
+                format_args!("failed to verify signature of validator {0}, whose timestamp {1} only has millisecond precision: it was likely truncated, eg. by an RPC proxy",
+                    e.validator_address, e.timestamp)
             },
 
         InvalidCommitValue
@@ -113,6 +123,14 @@ define_error! {
                     e.got, e.expected)
             },
 
+        NoTrustedAncestor
+            {
+                target: Height,
+            }
+            | e | {
+                format_args!("no trusted block below target height {0}", e.target)
+            },
+
         ChainIdMismatch
             {
                 got: String,
@@ -162,7 +180,7 @@ define_error! {
         FaultySigner
             {
                 signer: Id,
-                validator_set: ValidatorSet
+                validator_set: Box<ValidatorSet>
             }
             | e | {
                 format_args!(
@@ -171,6 +189,28 @@ define_error! {
                 )
             },
 
+        MissingLightBlockField
+            {
+                field: String,
+            }
+            | e | {
+                format_args!("missing light block field: {0}", e.field)
+            },
+
+        InvalidSignatureTimestamp
+            {
+                validator_address: ValidatorAddress,
+                timestamp: Time,
+                header_time: Time,
+                tolerance: Duration,
+            }
+            | e | {
+                format_args!(
+                    "signature of validator {} has timestamp {} too far from header time {} (tolerance: {:?})",
+                    e.validator_address, e.timestamp, e.header_time, e.tolerance
+                )
+            },
+
     }
 }
 
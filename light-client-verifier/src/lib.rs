@@ -3,6 +3,7 @@
 extern crate alloc;
 
 mod prelude;
+mod trace;
 
 pub mod errors;
 pub mod operations;
@@ -1,10 +1,13 @@
 //! Provides an interface and default implementation for the `CommitValidator` operation
 
+use core::time::Duration;
+
 use tendermint::block::CommitSig;
 
 use crate::{
     errors::VerificationError,
-    types::{SignedHeader, ValidatorSet},
+    prelude::*,
+    types::{SignedHeader, Time, ValidatorSet},
 };
 
 /// Validates the commit associated with a header against a validator set
@@ -52,10 +55,10 @@ pub trait CommitValidator: Send + Sync {
                 } => validator_address,
             };
 
-            if validator_set.validator(*validator_address).is_none() {
+            if validator_set.validator(validator_address).is_none() {
                 return Err(VerificationError::faulty_signer(
                     *validator_address,
-                    validator_set.clone(),
+                    Box::new(validator_set.clone()),
                 ));
             }
         }
@@ -69,3 +72,108 @@ pub trait CommitValidator: Send + Sync {
 pub struct ProdCommitValidator;
 
 impl CommitValidator for ProdCommitValidator {}
+
+/// Commit validator which, on top of the checks performed by
+/// [`ProdCommitValidator`], ensures that the timestamp of every signature for
+/// the block lies within `tolerance` of the header time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimestampCommitValidator {
+    /// How far a signature timestamp may be from the header time, either way
+    pub tolerance: Duration,
+}
+
+impl TimestampCommitValidator {
+    /// Constructs a commit validator with the given tolerance.
+    pub fn new(tolerance: Duration) -> Self {
+        Self { tolerance }
+    }
+}
+
+impl CommitValidator for TimestampCommitValidator {
+    fn validate_full(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+    ) -> Result<(), VerificationError> {
+        ProdCommitValidator.validate_full(signed_header, validator_set)?;
+
+        let header_time = signed_header.header.time;
+        for commit_sig in signed_header.commit.signatures.iter() {
+            if let CommitSig::BlockIdFlagCommit {
+                validator_address,
+                timestamp,
+                ..
+            } = commit_sig
+            {
+                if !is_within(*timestamp, header_time, self.tolerance) {
+                    return Err(VerificationError::invalid_signature_timestamp(
+                        *validator_address,
+                        *timestamp,
+                        header_time,
+                        self.tolerance,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_within(time: Time, reference: Time, tolerance: Duration) -> bool {
+    let divergence = time
+        .duration_since(reference)
+        .or_else(|_| reference.duration_since(time));
+    matches!(divergence, Ok(divergence) if divergence <= tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+
+    use super::*;
+    use crate::errors::VerificationErrorDetail;
+
+    fn light_block() -> (SignedHeader, ValidatorSet) {
+        let lb = TestgenLightBlock::new_default(1).generate().unwrap();
+        (lb.signed_header, lb.validators)
+    }
+
+    fn shift_first_signature(signed_header: &mut SignedHeader, by: Duration) -> Time {
+        match &mut signed_header.commit.signatures[0] {
+            CommitSig::BlockIdFlagCommit { timestamp, .. } => {
+                *timestamp = (*timestamp + by).unwrap();
+                *timestamp
+            },
+            _ => panic!("expected a commit signature"),
+        }
+    }
+
+    #[test]
+    fn signature_timestamps_within_tolerance_are_accepted() {
+        let (signed_header, validators) = light_block();
+        let validator = TimestampCommitValidator::new(Duration::from_secs(60));
+        assert!(validator.validate_full(&signed_header, &validators).is_ok());
+    }
+
+    #[test]
+    fn signature_timestamp_an_hour_off_is_flagged() {
+        let (mut signed_header, validators) = light_block();
+        let timestamp = shift_first_signature(&mut signed_header, Duration::from_secs(3600));
+
+        // Not checked unless enabled
+        assert!(ProdCommitValidator
+            .validate_full(&signed_header, &validators)
+            .is_ok());
+
+        let validator = TimestampCommitValidator::new(Duration::from_secs(60));
+        match validator.validate_full(&signed_header, &validators) {
+            Err(VerificationError(VerificationErrorDetail::InvalidSignatureTimestamp(e), _)) => {
+                assert_eq!(e.timestamp, timestamp);
+                assert_eq!(e.header_time, signed_header.header.time);
+                assert_eq!(e.tolerance, Duration::from_secs(60));
+            },
+            result => panic!("expected InvalidSignatureTimestamp, got {result:?}"),
+        }
+    }
+}
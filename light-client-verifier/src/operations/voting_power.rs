@@ -17,7 +17,8 @@ use tendermint::{
 use crate::{
     errors::VerificationError,
     prelude::*,
-    types::{Commit, SignedHeader, TrustThreshold, ValidatorSet},
+    trace::trace_tally,
+    types::{Commit, SignedHeader, Time, TrustThreshold, ValidatorSet},
 };
 
 /// Tally for the voting power computed by the `VotingPowerCalculator`
@@ -74,26 +75,28 @@ impl fmt::Display for VotingPowerTally {
 /// This trait provides default implementation of some helper functions.
 pub trait VotingPowerCalculator: Send + Sync {
     /// Compute the total voting power in a validator set
+    ///
+    /// The default implementation returns the total computed, with overflow
+    /// checks, when the validator set was constructed.
     fn total_power_of(&self, validator_set: &ValidatorSet) -> u64 {
-        validator_set
-            .validators()
-            .iter()
-            .fold(0u64, |total, val_info| total + val_info.power.value())
+        validator_set.total_voting_power().value()
     }
 
     /// Check that there is enough trust between an untrusted header and given
     /// trusted and untrusted validator sets.
     ///
     /// First of all, checks that enough validators from the
-    /// `trusted_validators` set signed the `untrusted_header` to reach given
-    /// `trust_threshold`.
+    /// `untrusted_validators` set signed the `untrusted_header` to reach
+    /// a trust threshold of ⅔, failing with `InsufficientSignersOverlap`
+    /// otherwise.
     ///
     /// Second of all, checks that enough validators from the
-    /// `untrusted_validators` set signed the `untrusted_header` to reach
-    /// a trust threshold of ⅔.
+    /// `trusted_validators` set signed the `untrusted_header` to reach given
+    /// `trust_threshold`, failing with `NotEnoughTrust` otherwise.
     ///
-    /// If both of those conditions aren’t met, it’s unspecified which error is
-    /// returned.
+    /// `NotEnoughTrust` is thus only returned for headers whose commit is
+    /// valid against their own validator set, which cannot be verified yet
+    /// because the trusted validators retain too little power in it.
     fn check_enough_trust_and_signers(
         &self,
         untrusted_header: &SignedHeader,
@@ -106,12 +109,14 @@ pub trait VotingPowerCalculator: Send + Sync {
             (trusted_validators, trust_threshold),
             (untrusted_validators, TrustThreshold::TWO_THIRDS),
         )?;
-        trusted_power
-            .check()
-            .map_err(VerificationError::not_enough_trust)?;
+        trace_tally!(untrusted_power, "untrusted validators voting power tally");
+        trace_tally!(trusted_power, "trusted validators voting power tally");
         untrusted_power
             .check()
             .map_err(VerificationError::insufficient_signers_overlap)?;
+        trusted_power
+            .check()
+            .map_err(VerificationError::not_enough_trust)?;
         Ok(())
     }
 
@@ -122,7 +127,10 @@ pub trait VotingPowerCalculator: Send + Sync {
         untrusted_validators: &ValidatorSet,
     ) -> Result<(), VerificationError> {
         let trust_threshold = TrustThreshold::TWO_THIRDS;
-        self.voting_power_in(untrusted_header, untrusted_validators, trust_threshold)?
+        let untrusted_power =
+            self.voting_power_in(untrusted_header, untrusted_validators, trust_threshold)?;
+        trace_tally!(untrusted_power, "untrusted validators voting power tally");
+        untrusted_power
             .check()
             .map_err(VerificationError::insufficient_signers_overlap)
     }
@@ -205,6 +213,7 @@ impl<V> Default for ProvidedVotingPowerCalculator<V> {
 /// A signed non-nil vote.
 struct NonAbsentCommitVote {
     signed_vote: SignedVote,
+    timestamp: Time,
     /// Flag indicating whether the signature has already been verified.
     verified: bool,
 }
@@ -249,6 +258,7 @@ impl NonAbsentCommitVote {
                 .ok_or_else(VerificationError::missing_signature)
                 .map(|signed_vote| Self {
                     signed_vote,
+                    timestamp,
                     verified: false,
                 }),
         )
@@ -258,6 +268,11 @@ impl NonAbsentCommitVote {
     pub fn validator_id(&self) -> account::Id {
         self.signed_vote.validator_id()
     }
+
+    /// The nanoseconds of the vote timestamp within its second.
+    fn timestamp_nanos(&self) -> i32 {
+        self.timestamp.to_protobuf_timestamp().1
+    }
 }
 
 /// Collection of non-absent commit votes.
@@ -269,6 +284,9 @@ struct NonAbsentCommitVotes {
     /// The buffer is reused for each canonical vote so that we allocate it
     /// once.
     sign_bytes: Vec<u8>,
+    /// Whether the vote timestamps look truncated to millisecond precision,
+    /// which makes their sign bytes differ from the signed ones.
+    truncated_timestamps: bool,
 }
 
 impl NonAbsentCommitVotes {
@@ -284,9 +302,13 @@ impl NonAbsentCommitVotes {
     /// buffer we’ll ever need is 166 bytes long.
     const SIGN_BYTES_INITIAL_CAPACITY: usize = 166;
 
-    pub fn new(signed_header: &SignedHeader) -> Result<Self, VerificationError> {
-        let mut votes = signed_header
-            .commit
+    /// Collects the non-absent votes of given commit.
+    ///
+    /// The sign bytes of each vote are reconstructed with given chain id,
+    /// which is part of what validators sign, so that votes of a commit for
+    /// another chain never have a valid signature.
+    pub fn new(commit: &Commit, chain_id: &chain::Id) -> Result<Self, VerificationError> {
+        let mut votes = commit
             .signatures
             .iter()
             .enumerate()
@@ -294,12 +316,7 @@ impl NonAbsentCommitVotes {
                 // We never have more than 2³¹ signatures so this always
                 // succeeds.
                 let idx = ValidatorIndex::try_from(idx).unwrap();
-                NonAbsentCommitVote::new(
-                    signature,
-                    idx,
-                    &signed_header.commit,
-                    &signed_header.header.chain_id,
-                )
+                NonAbsentCommitVote::new(signature, idx, commit, chain_id)
             })
             .collect::<Result<Vec<_>, VerificationError>>()?;
         votes.sort_unstable_by_key(NonAbsentCommitVote::validator_id);
@@ -310,22 +327,34 @@ impl NonAbsentCommitVotes {
             .windows(2)
             .find(|pair| pair[0].validator_id() == pair[1].validator_id());
         if let Some(pair) = duplicate {
-            Err(VerificationError::duplicate_validator(
+            return Err(VerificationError::duplicate_validator(
                 pair[0].validator_id(),
-            ))
-        } else {
-            Ok(Self {
-                votes,
-                sign_bytes: Vec::with_capacity(Self::SIGN_BYTES_INITIAL_CAPACITY),
-            })
+            ));
         }
+
+        // Votes carry nanosecond timestamps, which only land on a whole number
+        // of milliseconds once in a million, while proxies truncate all the
+        // timestamps of a commit alike, possibly down to a whole second.
+        // Timestamps which are all whole seconds are not telling on their own,
+        // as some test chains use them.
+        let truncated_timestamps = votes
+            .iter()
+            .all(|vote| vote.timestamp_nanos() % 1_000_000 == 0)
+            && votes.iter().any(|vote| vote.timestamp_nanos() != 0);
+
+        Ok(Self {
+            votes,
+            sign_bytes: Vec::with_capacity(Self::SIGN_BYTES_INITIAL_CAPACITY),
+            truncated_timestamps,
+        })
     }
 
     /// Looks up a vote cast by given validator.
     ///
     /// If the validator didn’t cast a vote or voted for `nil`, returns
     /// `Ok(false)`.  Otherwise, if the vote had valid signature, returns
-    /// `Ok(true)`.  If the vote had invalid signature, returns `Err`.
+    /// `Ok(true)`.  If the vote had invalid signature, returns `Err`, with
+    /// `TimestampPrecisionLost` if its timestamp looks truncated.
     pub fn has_voted<V: signature::Verifier>(
         &mut self,
         validator: &validator::Info,
@@ -347,6 +376,12 @@ impl NonAbsentCommitVotes {
             validator
                 .verify_signature::<V>(sign_bytes, vote.signed_vote.signature())
                 .map_err(|_| {
+                    if self.truncated_timestamps {
+                        return VerificationError::timestamp_precision_lost(
+                            validator.address,
+                            vote.timestamp,
+                        );
+                    }
                     VerificationError::invalid_signature(
                         vote.signed_vote.signature().as_bytes().to_vec(),
                         Box::new(validator.clone()),
@@ -372,7 +407,8 @@ impl<V: signature::Verifier> VotingPowerCalculator for ProvidedVotingPowerCalcul
         validator_set: &ValidatorSet,
         trust_threshold: TrustThreshold,
     ) -> Result<VotingPowerTally, VerificationError> {
-        let mut votes = NonAbsentCommitVotes::new(signed_header)?;
+        let mut votes =
+            NonAbsentCommitVotes::new(&signed_header.commit, &signed_header.header.chain_id)?;
         voting_power_in_impl::<V>(
             &mut votes,
             validator_set,
@@ -387,7 +423,8 @@ impl<V: signature::Verifier> VotingPowerCalculator for ProvidedVotingPowerCalcul
         first_set: (&ValidatorSet, TrustThreshold),
         second_set: (&ValidatorSet, TrustThreshold),
     ) -> Result<(VotingPowerTally, VotingPowerTally), VerificationError> {
-        let mut votes = NonAbsentCommitVotes::new(signed_header)?;
+        let mut votes =
+            NonAbsentCommitVotes::new(&signed_header.commit, &signed_header.header.chain_id)?;
         let first_tally = voting_power_in_impl::<V>(
             &mut votes,
             first_set.0,
@@ -509,6 +546,34 @@ mod tests {
         assert_eq!(result_ok.unwrap(), EXPECTED_RESULT);
     }
 
+    #[test]
+    fn test_signatures_matched_by_address() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+        let trust_threshold = TrustThreshold::default();
+
+        let light_block: LightBlock = TestgenLightBlock::new_default(10)
+            .generate()
+            .unwrap()
+            .into();
+        let expected = vp_calculator
+            .voting_power_in(
+                &light_block.signed_header,
+                &light_block.validators,
+                trust_threshold,
+            )
+            .unwrap();
+
+        // Signatures are matched to validators by their address, not by their
+        // position in the commit, which the signed votes do not depend on.
+        let mut signed_header = light_block.signed_header.clone();
+        signed_header.commit.signatures.reverse();
+        assert_ne!(signed_header.commit, light_block.signed_header.commit);
+        let result = vp_calculator
+            .voting_power_in(&signed_header, &light_block.validators, trust_threshold)
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_one_invalid_signature() {
         let vp_calculator = ProdVotingPowerCalculator::default();
@@ -518,6 +583,7 @@ mod tests {
         let mut commit = testgen_lb.commit.clone().unwrap();
         let mut votes = commit.votes.unwrap();
         let vote = votes.pop().unwrap();
+        let faulty_address = vote.validator.clone().unwrap().generate().unwrap().address;
         let header = vote.clone().header.unwrap().chain_id("bad-chain");
         votes.push(vote.header(header));
 
@@ -532,11 +598,100 @@ mod tests {
         );
 
         match result_err {
-            Err(VerificationError(VerificationErrorDetail::InvalidSignature(_), _)) => {},
+            Err(VerificationError(VerificationErrorDetail::InvalidSignature(e), _)) => {
+                // The error names the validator whose signature is invalid
+                assert_eq!(e.validator.address, faulty_address);
+                let message = VerificationErrorDetail::InvalidSignature(e).to_string();
+                assert!(message.contains(&faulty_address.to_string()), "{message}");
+            },
             _ => panic!("expected InvalidSignature error"),
         }
     }
 
+    #[test]
+    fn test_truncated_timestamp() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+        let trust_threshold = TrustThreshold::default();
+
+        let time = Time::from_unix_timestamp(10, 123_456_789).unwrap();
+        let header = Header::new(&ValidatorSet::new(vec!["a", "b"]).validators.unwrap())
+            .time(time)
+            .chain_id("chain");
+        let mut light_block: LightBlock = TestgenLightBlock::new_default_with_header(header)
+            .generate()
+            .unwrap()
+            .into();
+
+        // The signatures verify with the full timestamps
+        let tally = vp_calculator.voting_power_in(
+            &light_block.signed_header,
+            &light_block.validators,
+            trust_threshold,
+        );
+        assert!(tally.is_ok(), "{tally:?}");
+
+        // Truncate the timestamps to millisecond precision, as some proxies do
+        let truncated = Time::from_unix_timestamp(10, 123_000_000).unwrap();
+        for sig in light_block.signed_header.commit.signatures.iter_mut() {
+            if let CommitSig::BlockIdFlagCommit { timestamp, .. } = sig {
+                *timestamp = truncated;
+            }
+        }
+
+        let result_err = vp_calculator.voting_power_in(
+            &light_block.signed_header,
+            &light_block.validators,
+            trust_threshold,
+        );
+
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::TimestampPrecisionLost(e), _)) => {
+                assert_eq!(e.timestamp, truncated);
+            },
+            result => panic!("expected TimestampPrecisionLost error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_timestamp_truncated_to_whole_second() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+        let trust_threshold = TrustThreshold::default();
+
+        let time = Time::from_unix_timestamp(10, 456_789).unwrap();
+        let header = Header::new(&ValidatorSet::new(vec!["a", "b", "c"]).validators.unwrap())
+            .time(time)
+            .chain_id("chain");
+        let mut light_block: LightBlock = TestgenLightBlock::new_default_with_header(header)
+            .generate()
+            .unwrap()
+            .into();
+
+        // Truncating to millisecond precision leaves a whole second, which
+        // only the precision of the last timestamp gives away
+        let truncated = Time::from_unix_timestamp(10, 0).unwrap();
+        let last_truncated = Time::from_unix_timestamp(11, 123_000_000).unwrap();
+        let signatures = &mut light_block.signed_header.commit.signatures;
+        let last = signatures.len() - 1;
+        for (i, sig) in signatures.iter_mut().enumerate() {
+            if let CommitSig::BlockIdFlagCommit { timestamp, .. } = sig {
+                *timestamp = if i == last { last_truncated } else { truncated };
+            }
+        }
+
+        let result_err = vp_calculator.voting_power_in(
+            &light_block.signed_header,
+            &light_block.validators,
+            trust_threshold,
+        );
+
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::TimestampPrecisionLost(e), _)) => {
+                assert_eq!(e.timestamp, truncated);
+            },
+            result => panic!("expected TimestampPrecisionLost error, got {result:?}"),
+        }
+    }
+
     #[test]
     fn test_all_signatures_invalid() {
         let vp_calculator = ProdVotingPowerCalculator::default();
@@ -559,6 +714,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sign_bytes_include_chain_id() {
+        type Verifier = tendermint::crypto::default::signature::Verifier;
+
+        let light_block: LightBlock = TestgenLightBlock::new_default(10)
+            .generate()
+            .unwrap()
+            .into();
+        let commit = &light_block.signed_header.commit;
+        let chain_id = &light_block.signed_header.header.chain_id;
+        let validators = light_block.validators.validators();
+        assert!(!validators.is_empty());
+
+        // Every signature verifies with the chain id of the header
+        let mut votes = NonAbsentCommitVotes::new(commit, chain_id).unwrap();
+        for validator in validators {
+            assert!(votes.has_voted::<Verifier>(validator).unwrap());
+        }
+
+        // ... and none with another chain id
+        let other_chain_id = chain::Id::try_from("other-chain").unwrap();
+        assert_ne!(chain_id, &other_chain_id);
+        let mut votes = NonAbsentCommitVotes::new(commit, &other_chain_id).unwrap();
+        for validator in validators {
+            match votes.has_voted::<Verifier>(validator) {
+                Err(VerificationError(VerificationErrorDetail::InvalidSignature(_), _)) => {},
+                result => panic!("expected InvalidSignature error, got {result:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_signatures_from_diff_valset() {
         let vp_calculator = ProdVotingPowerCalculator::default();
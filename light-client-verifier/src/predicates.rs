@@ -100,6 +100,9 @@ pub trait VerificationPredicates: Send + Sync {
     }
 
     /// Check that the trusted header is within the trusting period, adjusting for clock drift.
+    ///
+    /// The trusted header expires at `trusted_header_time + trusting_period`: from that
+    /// instant on, it can no longer be used for verification.
     fn is_within_trust_period(
         &self,
         trusted_header_time: Time,
@@ -379,6 +382,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_within_trust_period_boundaries() {
+        let val = Validator::new("val-1");
+        let header = Header::new(&[val]).generate().unwrap();
+
+        let vp = ProdPredicates;
+        let trusting_period = Duration::new(1000, 0);
+        let expires_at = (header.time + trusting_period).unwrap();
+        let one_nano = Duration::new(0, 1);
+
+        // 1. just before expiry, the header is still trusted
+        let now = (expires_at - one_nano).unwrap();
+        assert!(vp
+            .is_within_trust_period(header.time, trusting_period, now)
+            .is_ok());
+
+        // 2. exactly at expiry, the header has expired
+        let now = expires_at;
+        match vp.is_within_trust_period(header.time, trusting_period, now) {
+            Err(VerificationError(VerificationErrorDetail::NotWithinTrustPeriod(e), _)) => {
+                assert_eq!(e.expires_at, expires_at);
+                assert_eq!(e.now, now);
+            },
+            _ => panic!("expected NotWithinTrustPeriod error"),
+        }
+
+        // 3. just after expiry, the header has expired
+        let now = (expires_at + one_nano).unwrap();
+        match vp.is_within_trust_period(header.time, trusting_period, now) {
+            Err(VerificationError(VerificationErrorDetail::NotWithinTrustPeriod(e), _)) => {
+                assert_eq!(e.expires_at, expires_at);
+                assert_eq!(e.now, now);
+            },
+            _ => panic!("expected NotWithinTrustPeriod error"),
+        }
+    }
+
     #[test]
     fn test_is_header_from_past() {
         let val = Validator::new("val-1");
@@ -589,7 +629,7 @@ mod tests {
                         .unwrap()
                 );
 
-                assert_eq!(e.validator_set, val_set_with_faulty_signer);
+                assert_eq!(*e.validator_set, val_set_with_faulty_signer);
             },
             _ => panic!("expected FaultySigner error"),
         }
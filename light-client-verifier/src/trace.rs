@@ -0,0 +1,61 @@
+//! Optional instrumentation of the verifier with [`tracing`] events, enabled
+//! by the `tracing` feature.
+//!
+//! [`tracing`]: https://docs.rs/tracing
+
+/// Emit a `tracing` event at the debug level if the `tracing` feature is
+/// enabled, and do nothing otherwise.
+macro_rules! trace_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+/// Enter a `tracing` span for the verification of the given untrusted block
+/// against the given trusted state, if the `tracing` feature is enabled.
+///
+/// The span carries the height of both blocks, and records the trust ratio
+/// achieved by the commit once the voting power is tallied.
+macro_rules! verification_span {
+    ($name:literal, $untrusted:expr, $trusted:expr) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            $name,
+            height = %$untrusted.height(),
+            trusted_height = %$trusted.height,
+            trust_ratio = tracing::field::Empty,
+        )
+        .entered();
+    };
+}
+
+/// Record the trust ratio achieved by the given tally on the current
+/// verification span, and emit an event with the details of the tally.
+macro_rules! trace_tally {
+    ($tally:expr, $message:literal) => {
+        #[cfg(feature = "tracing")]
+        {
+            let tally = &$tally;
+            tracing::Span::current().record("trust_ratio", $crate::trace::ratio(tally));
+            tracing::debug!(
+                tallied = tally.tallied,
+                total = tally.total,
+                trust_threshold = %tally.trust_threshold,
+                $message
+            );
+        }
+    };
+}
+
+pub(crate) use {trace_event, trace_tally, verification_span};
+
+/// The ratio of the tallied voting power to the total voting power.
+#[cfg(feature = "tracing")]
+pub(crate) fn ratio(tally: &crate::operations::VotingPowerTally) -> f64 {
+    if tally.total == 0 {
+        0.0
+    } else {
+        tally.tallied as f64 / tally.total as f64
+    }
+}
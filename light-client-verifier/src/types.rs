@@ -118,6 +118,12 @@ pub struct LightBlock {
 }
 
 impl LightBlock {
+    /// Returns a new builder for a light block, which checks that the
+    /// validator sets match the hashes in the header.
+    pub fn builder() -> LightBlockBuilder {
+        LightBlockBuilder::default()
+    }
+
     /// Constructs a new light block
     pub fn new(
         signed_header: SignedHeader,
@@ -170,6 +176,84 @@ impl LightBlock {
             next_validators: Some(&self.next_validators),
         }
     }
+
+    /// Whether both light blocks record the same block, ie. have the same
+    /// signed header and validator sets, regardless of which peer provided them.
+    pub fn same_block(&self, other: &LightBlock) -> bool {
+        self.signed_header == other.signed_header
+            && self.validators == other.validators
+            && self.next_validators == other.next_validators
+    }
+}
+
+/// A builder of `LightBlock` with a fluent API.
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct LightBlockBuilder {
+    signed_header: Option<SignedHeader>,
+    validators: Option<ValidatorSet>,
+    next_validators: Option<ValidatorSet>,
+    provider: Option<PeerId>,
+}
+
+impl LightBlockBuilder {
+    /// Set the header and commit of the block.
+    pub fn signed_header(mut self, signed_header: SignedHeader) -> Self {
+        self.signed_header = Some(signed_header);
+        self
+    }
+
+    /// Set the validator set at the block height.
+    pub fn validators(mut self, validators: ValidatorSet) -> Self {
+        self.validators = Some(validators);
+        self
+    }
+
+    /// Set the validator set at the next block height.
+    pub fn next_validators(mut self, next_validators: ValidatorSet) -> Self {
+        self.next_validators = Some(next_validators);
+        self
+    }
+
+    /// Set the peer ID of the node that provided the block.
+    pub fn provider(mut self, provider: PeerId) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Builds the light block.
+    ///
+    /// Fails with `VerificationError::MissingLightBlockField` if any part of
+    /// the block was not set, and with `VerificationError::InvalidValidatorSet`
+    /// or `VerificationError::InvalidNextValidatorSet` if the validator sets
+    /// do not hash to the ones the header commits to.
+    #[cfg(feature = "rust-crypto")]
+    pub fn build(self) -> Result<LightBlock, crate::errors::VerificationError> {
+        use crate::{
+            errors::VerificationError,
+            predicates::{ProdPredicates, VerificationPredicates},
+        };
+
+        let missing = |field: &str| VerificationError::missing_light_block_field(field.into());
+        let signed_header = self.signed_header.ok_or_else(|| missing("signed_header"))?;
+        let validators = self.validators.ok_or_else(|| missing("validators"))?;
+        let next_validators = self
+            .next_validators
+            .ok_or_else(|| missing("next_validators"))?;
+        let provider = self.provider.ok_or_else(|| missing("provider"))?;
+
+        let predicates = ProdPredicates;
+        predicates.validator_sets_match(&validators, signed_header.header.validators_hash)?;
+        predicates
+            .next_validators_match(&next_validators, signed_header.header.next_validators_hash)?;
+
+        Ok(LightBlock::new(
+            signed_header,
+            validators,
+            next_validators,
+            provider,
+        ))
+    }
 }
 
 /// Contains the local status information, like the latest height, latest block and valset hashes,
@@ -210,6 +294,111 @@ impl LatestStatus {
 #[cfg(test)]
 mod tests {
 
+    #[cfg(feature = "rust-crypto")]
+    mod light_block {
+        use tendermint_testgen::{
+            light_block::TmLightBlock, Generator, LightBlock as TestgenLightBlock, Validator,
+        };
+
+        use crate::{
+            errors::{VerificationError, VerificationErrorDetail},
+            prelude::*,
+            types::{LightBlock, ValidatorSet},
+        };
+
+        fn generated() -> TmLightBlock {
+            TestgenLightBlock::new_default(1).generate().unwrap()
+        }
+
+        fn builder(lb: TmLightBlock) -> crate::types::LightBlockBuilder {
+            LightBlock::builder()
+                .signed_header(lb.signed_header)
+                .validators(lb.validators)
+                .next_validators(lb.next_validators)
+                .provider(lb.provider)
+        }
+
+        fn other_validator_set() -> ValidatorSet {
+            ValidatorSet::without_proposer(vec![Validator::new("other").generate().unwrap()])
+        }
+
+        #[test]
+        fn builder_succeeds_with_consistent_validator_sets() {
+            let lb = generated();
+            let built = builder(lb.clone()).build().unwrap();
+            assert_eq!(
+                built,
+                LightBlock::new(
+                    lb.signed_header,
+                    lb.validators,
+                    lb.next_validators,
+                    lb.provider
+                )
+            );
+        }
+
+        #[test]
+        fn builder_rejects_mismatched_validators() {
+            let result = builder(generated())
+                .validators(other_validator_set())
+                .build();
+            assert!(matches!(
+                result,
+                Err(VerificationError(
+                    VerificationErrorDetail::InvalidValidatorSet(_),
+                    _
+                ))
+            ));
+        }
+
+        #[test]
+        fn builder_rejects_mismatched_next_validators() {
+            let result = builder(generated())
+                .next_validators(other_validator_set())
+                .build();
+            assert!(matches!(
+                result,
+                Err(VerificationError(
+                    VerificationErrorDetail::InvalidNextValidatorSet(_),
+                    _
+                ))
+            ));
+        }
+
+        #[test]
+        fn same_block_ignores_provider() {
+            let lb = generated();
+            let block = builder(lb.clone()).build().unwrap();
+            let other_provider = builder(lb)
+                .provider("0123456789ABCDEF0123456789ABCDEF01234567".parse().unwrap())
+                .build()
+                .unwrap();
+
+            assert_ne!(block, other_provider);
+            assert!(block.same_block(&other_provider));
+
+            let next = TestgenLightBlock::new_default(1).next().generate().unwrap();
+            let next = builder(next).build().unwrap();
+            assert!(!block.same_block(&next));
+        }
+
+        #[test]
+        fn builder_requires_all_fields() {
+            let lb = generated();
+            let result = LightBlock::builder()
+                .signed_header(lb.signed_header)
+                .validators(lb.validators)
+                .provider(lb.provider)
+                .build();
+            match result {
+                Err(VerificationError(VerificationErrorDetail::MissingLightBlockField(e), _)) => {
+                    assert_eq!(e.field, "next_validators")
+                },
+                result => panic!("expected MissingLightBlockField, got {result:?}"),
+            }
+        }
+    }
+
     mod status {
         use Status::*;
 
@@ -3,10 +3,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::{ErrorExt, VerificationError, VerificationErrorDetail},
+    errors::{ErrorExt, NotWithinTrustPeriodSubdetail, VerificationError, VerificationErrorDetail},
     operations::{voting_power::VotingPowerTally, CommitValidator, VotingPowerCalculator},
     options::Options,
     predicates::VerificationPredicates,
+    trace::{trace_event, verification_span},
     types::{Time, TrustedBlockState, UntrustedBlockState},
 };
 
@@ -25,6 +26,9 @@ pub enum Verdict {
     /// The minimum voting power threshold is not reached,
     /// the block cannot be trusted yet.
     NotEnoughTrust(VotingPowerTally),
+    /// The trusted state is outside of the trusting period, the block
+    /// cannot be verified against it.
+    Expired(NotWithinTrustPeriodSubdetail),
     /// Verification failed, the block is invalid.
     Invalid(VerificationErrorDetail),
 }
@@ -33,6 +37,9 @@ impl From<Result<(), VerificationError>> for Verdict {
     fn from(result: Result<(), VerificationError>) -> Self {
         match result {
             Ok(()) => Self::Success,
+            Err(VerificationError(VerificationErrorDetail::NotWithinTrustPeriod(e), _)) => {
+                Self::Expired(e)
+            },
             Err(VerificationError(e, _)) => match e.not_enough_trust() {
                 Some(tally) => Self::NotEnoughTrust(tally),
                 _ => Self::Invalid(e),
@@ -123,6 +130,35 @@ where
             untrusted.signed_header.header.validators_hash,
         ));
 
+        self.verify_next_validators_and_commit(untrusted)
+    }
+
+    /// Validates an `UntrustedBlockState` against the given `TrustedBlockState`.
+    ///
+    /// This performs the same checks as [`PredicateVerifier::verify_validator_sets()`],
+    /// except when the untrusted block is adjacent to the trusted one and carries the
+    /// very validator set the trusted block committed to. In that case the untrusted
+    /// validator set is known to hash to `trusted.next_validators_hash`, and hashing
+    /// it again is skipped. The commit is verified in full either way.
+    fn verify_validator_sets_against_trusted(
+        &self,
+        untrusted: &UntrustedBlockState<'_>,
+        trusted: &TrustedBlockState<'_>,
+    ) -> Verdict {
+        let is_adjacent_with_same_validators = untrusted.height() == trusted.height.increment()
+            && untrusted.signed_header.header.validators_hash == trusted.next_validators_hash
+            && untrusted.validators == trusted.next_validators;
+
+        if is_adjacent_with_same_validators {
+            self.verify_next_validators_and_commit(untrusted)
+        } else {
+            self.verify_validator_sets(untrusted)
+        }
+    }
+
+    /// Validates the parts of an `UntrustedBlockState` which do not depend on
+    /// its current validator set hash.
+    fn verify_next_validators_and_commit(&self, untrusted: &UntrustedBlockState<'_>) -> Verdict {
         // Ensure the header next validator hashes match the given next validators
         if let Some(untrusted_next_validators) = untrusted.next_validators {
             verdict!(self.predicates.next_validators_match(
@@ -138,11 +174,13 @@ where
         ));
 
         // Additional implementation specific validation
-        verdict!(self.predicates.valid_commit(
+        let result = self.predicates.valid_commit(
             untrusted.signed_header,
             untrusted.validators,
             &self.commit_validator,
-        ));
+        );
+        trace_event!(valid = result.is_ok(), "commit validation");
+        verdict!(result);
 
         Verdict::Success
     }
@@ -157,11 +195,17 @@ where
         now: Time,
     ) -> Verdict {
         // Ensure the latest trusted header hasn't expired
-        verdict!(self.predicates.is_within_trust_period(
+        let result = self.predicates.is_within_trust_period(
             trusted.header_time,
             options.trusting_period,
             now,
-        ));
+        );
+        trace_event!(
+            within_trust_period = result.is_ok(),
+            trusted_time = %trusted.header_time,
+            "trust period check"
+        );
+        verdict!(result);
 
         // Check that the untrusted block is more recent than the trusted state
         verdict!(self
@@ -254,6 +298,11 @@ where
                 &self.voting_power_calculator,
             )
         };
+        trace_event!(
+            sufficient = result.is_ok(),
+            skipping = need_both,
+            "commit voting power check"
+        );
         verdict!(result);
         Verdict::Success
     }
@@ -292,6 +341,10 @@ where
     /// `trusted.next_validators.hash() == trusted.next_validators_hash`,
     /// as typically the `trusted.next_validators` validator set comes from the relayer,
     /// and `trusted.next_validators_hash` is the hash stored on chain.
+    ///
+    /// When the untrusted block directly follows the trusted one and carries the
+    /// trusted `next_validators`, the untrusted validator set is not re-hashed,
+    /// relying on the above.
     fn verify_update_header(
         &self,
         untrusted: UntrustedBlockState<'_>,
@@ -299,7 +352,9 @@ where
         options: &Options,
         now: Time,
     ) -> Verdict {
-        ensure_verdict_success!(self.verify_validator_sets(&untrusted));
+        verification_span!("verify_update_header", untrusted, trusted);
+
+        ensure_verdict_success!(self.verify_validator_sets_against_trusted(&untrusted, &trusted));
         ensure_verdict_success!(self.validate_against_trusted(&untrusted, &trusted, options, now));
         ensure_verdict_success!(self.check_header_is_from_past(&untrusted, options, now));
         ensure_verdict_success!(self.verify_commit_against_trusted(&untrusted, &trusted, options));
@@ -310,6 +365,10 @@ where
     /// Verify a header received in `MsgSubmitMisbehaviour`.
     /// The verification for these headers is a bit more relaxed in order to catch FLA attacks.
     /// In particular the "header in the future" check for the header should be skipped.
+    ///
+    /// Unlike in `verify_update_header`, the untrusted validator set is always
+    /// re-hashed, since the trusted state of a misbehaviour submission does not
+    /// necessarily come from the light client's own store.
     fn verify_misbehaviour_header(
         &self,
         untrusted: UntrustedBlockState<'_>,
@@ -317,6 +376,8 @@ where
         options: &Options,
         now: Time,
     ) -> Verdict {
+        verification_span!("verify_misbehaviour_header", untrusted, trusted);
+
         ensure_verdict_success!(self.verify_validator_sets(&untrusted));
         ensure_verdict_success!(self.validate_against_trusted(&untrusted, &trusted, options, now));
         ensure_verdict_success!(self.verify_commit_against_trusted(&untrusted, &trusted, options));
@@ -331,15 +392,19 @@ pub type ProdVerifier =
 
 #[cfg(test)]
 mod tests {
-    use alloc::{borrow::ToOwned, string::ToString};
+    use alloc::{borrow::ToOwned, string::ToString, vec};
     use core::{ops::Sub, time::Duration};
 
-    use tendermint::Time;
-    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+    use tendermint::{block::CommitSig, hash::AppHash, Time};
+    use tendermint_testgen::{
+        light_block::LightBlock as TestgenLightBlock, Generator, Header, Validator,
+    };
 
     use crate::{
-        errors::VerificationErrorDetail, options::Options, types::LightBlock, ProdVerifier,
-        Verdict, Verifier,
+        errors::VerificationErrorDetail,
+        options::Options,
+        types::{LightBlock, TrustedBlockState, UntrustedBlockState, ValidatorSet},
+        ProdVerifier, Verdict, Verifier,
     };
 
     #[allow(dead_code)]
@@ -399,4 +464,606 @@ mod tests {
             v => panic!("expected ChainIdMismatch error, got: {:?}", v),
         }
     }
+
+    #[test]
+    fn test_generated_signed_header_passes_verify_commit() {
+        let validators = [
+            Validator::new("a").voting_power(30),
+            Validator::new("b").voting_power(30),
+            Validator::new("c").voting_power(40),
+        ];
+        let header = Header::new(&validators)
+            .height(5)
+            .time(Time::from_unix_timestamp(5, 0).unwrap())
+            .chain_id("chain");
+
+        let signed_header = header.signed_header().unwrap();
+        let validator_set = ValidatorSet::without_proposer(
+            validators.iter().map(|v| v.generate().unwrap()).collect(),
+        );
+        assert_eq!(signed_header.header.height.value(), 5);
+        assert_eq!(signed_header.header.validators_hash, validator_set.hash());
+
+        let untrusted = UntrustedBlockState {
+            signed_header: &signed_header,
+            validators: &validator_set,
+            next_validators: None,
+        };
+        let vp = ProdVerifier::default();
+        assert_eq!(vp.verify_validator_sets(&untrusted), Verdict::Success);
+        assert_eq!(vp.verify_commit(&untrusted), Verdict::Success);
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn test_secp256k1_signed_header_passes_verify_commit() {
+        use tendermint::PublicKey;
+        use tendermint_testgen::validator::KeyType;
+
+        let validators = [
+            Validator::new("a").voting_power(30),
+            Validator::new("b")
+                .voting_power(30)
+                .key_type(KeyType::Secp256k1),
+            Validator::new("c")
+                .voting_power(40)
+                .key_type(KeyType::Secp256k1),
+        ];
+        let header = Header::new(&validators)
+            .height(5)
+            .time(Time::from_unix_timestamp(5, 0).unwrap())
+            .chain_id("chain");
+
+        let signed_header = header.signed_header().unwrap();
+        let validator_set = ValidatorSet::without_proposer(
+            validators.iter().map(|v| v.generate().unwrap()).collect(),
+        );
+        let secp256k1_validators = validator_set
+            .validators()
+            .iter()
+            .filter(|v| matches!(v.pub_key, PublicKey::Secp256k1(_)))
+            .count();
+        assert_eq!(secp256k1_validators, 2);
+
+        let untrusted = UntrustedBlockState {
+            signed_header: &signed_header,
+            validators: &validator_set,
+            next_validators: None,
+        };
+        let vp = ProdVerifier::default();
+        assert_eq!(vp.verify_validator_sets(&untrusted), Verdict::Success);
+        assert_eq!(vp.verify_commit(&untrusted), Verdict::Success);
+
+        // Corrupting the secp256k1 signature of "c" leaves less than 2/3 of the power
+        let address = validators[2].generate().unwrap().address;
+        let mut tampered = signed_header.clone();
+        for sig in &mut tampered.commit.signatures {
+            if let CommitSig::BlockIdFlagCommit {
+                validator_address,
+                signature: Some(signature),
+                ..
+            } = sig
+            {
+                if *validator_address == address {
+                    let mut bytes = signature.as_bytes().to_vec();
+                    bytes[10] ^= 0xff;
+                    *signature = bytes.try_into().unwrap();
+                }
+            }
+        }
+        let untrusted = UntrustedBlockState {
+            signed_header: &tampered,
+            ..untrusted
+        };
+        assert_ne!(vp.verify_commit(&untrusted), Verdict::Success);
+    }
+
+    /// Runs every check of `verify_update_header` without the adjacent-block fast path.
+    fn verify_update_header_general(
+        vp: &ProdVerifier,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        ensure_verdict_success!(vp.verify_validator_sets(&untrusted));
+        ensure_verdict_success!(vp.validate_against_trusted(&untrusted, &trusted, options, now));
+        ensure_verdict_success!(vp.check_header_is_from_past(&untrusted, options, now));
+        ensure_verdict_success!(vp.verify_commit_against_trusted(&untrusted, &trusted, options));
+
+        Verdict::Success
+    }
+
+    #[test]
+    fn test_adjacent_fast_path_matches_general_path() {
+        let now = Time::now();
+        let vp = ProdVerifier::default();
+        let opt = Options {
+            trust_threshold: Default::default(),
+            trusting_period: Duration::from_secs(60),
+            clock_drift: Default::default(),
+        };
+
+        let testgen_block_1 = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "chain".to_owned(),
+            now.sub(Duration::from_secs(20)).unwrap(),
+            1u64,
+        );
+        let light_block_1: LightBlock = testgen_block_1.generate().unwrap().into();
+        let light_block_2: LightBlock = testgen_block_1.next().generate().unwrap().into();
+
+        // A valid adjacent block
+        let fast = vp.verify_update_header(
+            light_block_2.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        let general = verify_update_header_general(
+            &vp,
+            light_block_2.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        assert_eq!(fast, Verdict::Success);
+        assert_eq!(fast, general);
+
+        // An adjacent block whose header does not match its commit
+        let mut tampered = light_block_2.clone();
+        tampered.signed_header.header.app_hash = AppHash::try_from(vec![0xff; 32]).unwrap();
+        let fast = vp.verify_update_header(
+            tampered.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        let general = verify_update_header_general(
+            &vp,
+            tampered.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        assert!(matches!(
+            fast,
+            Verdict::Invalid(VerificationErrorDetail::InvalidCommitValue(_))
+        ));
+        assert_eq!(fast, general);
+
+        // An adjacent block whose validator set does not match its header
+        let mut tampered = light_block_2.clone();
+        tampered.validators = light_block_1.validators.clone();
+        tampered.signed_header.header.validators_hash = Default::default();
+        let fast = vp.verify_update_header(
+            tampered.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        let general = verify_update_header_general(
+            &vp,
+            tampered.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &opt,
+            now,
+        );
+        assert!(matches!(
+            fast,
+            Verdict::Invalid(VerificationErrorDetail::InvalidValidatorSet(_))
+        ));
+        assert_eq!(fast, general);
+    }
+
+    #[test]
+    fn test_misbehaviour_header_rehashes_adjacent_validators() {
+        let now = Time::now();
+        let vp = ProdVerifier::default();
+
+        let testgen_block_1 = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "chain".to_owned(),
+            now.sub(Duration::from_secs(20)).unwrap(),
+            1u64,
+        );
+        let mut light_block_1: LightBlock = testgen_block_1.generate().unwrap().into();
+        let mut light_block_2: LightBlock = testgen_block_1.next().generate().unwrap().into();
+
+        // The trusted next validators do not hash to the trusted next
+        // validators hash, and the adjacent block carries them.
+        let other = light_block(
+            &[Validator::new("other")],
+            1,
+            now.sub(Duration::from_secs(20)).unwrap(),
+        );
+        light_block_1.next_validators = other.validators.clone();
+        light_block_2.validators = other.validators;
+
+        let verdict = vp.verify_misbehaviour_header(
+            light_block_2.as_untrusted_state(),
+            light_block_1.as_trusted_state(),
+            &options(),
+            now,
+        );
+        assert!(matches!(
+            verdict,
+            Verdict::Invalid(VerificationErrorDetail::InvalidValidatorSet(_))
+        ));
+    }
+
+    fn options() -> Options {
+        Options {
+            trust_threshold: Default::default(),
+            trusting_period: Duration::from_secs(60),
+            clock_drift: Default::default(),
+        }
+    }
+
+    fn light_block(validators: &[Validator], height: u64, time: Time) -> LightBlock {
+        let header = Header::new(validators)
+            .height(height)
+            .chain_id("chain")
+            .next_validators(validators)
+            .time(time);
+        TestgenLightBlock::new_default_with_header(header)
+            .generate()
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing_events {
+        extern crate std;
+
+        use alloc::{format, string::String, vec::Vec};
+        use core::fmt;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Metadata, Subscriber,
+        };
+        use tracing_core::span::Current;
+
+        use super::*;
+
+        /// The name and fields of a span or event.
+        #[derive(Debug, Default)]
+        struct Record {
+            name: String,
+            fields: Vec<(String, String)>,
+        }
+
+        impl Record {
+            fn field(&self, name: &str) -> Option<&str> {
+                self.fields
+                    .iter()
+                    .rev()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| v.as_str())
+            }
+        }
+
+        impl Visit for Record {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.fields
+                    .push((field.name().to_string(), format!("{value:?}")));
+            }
+        }
+
+        /// A subscriber capturing every span and event.
+        #[derive(Clone, Default)]
+        struct Capture {
+            spans: Arc<Mutex<Vec<(&'static Metadata<'static>, Record)>>>,
+            entered: Arc<Mutex<Vec<span::Id>>>,
+            events: Arc<Mutex<Vec<Record>>>,
+        }
+
+        impl Subscriber for Capture {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+                let mut record = Record {
+                    name: attrs.metadata().name().to_string(),
+                    ..Default::default()
+                };
+                attrs.record(&mut record);
+                let mut spans = self.spans.lock().unwrap();
+                spans.push((attrs.metadata(), record));
+                span::Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, id: &span::Id, values: &span::Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                values.record(&mut spans[id.into_u64() as usize - 1].1);
+            }
+
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut record = Record::default();
+                event.record(&mut record);
+                record.name = record.field("message").unwrap_or_default().to_string();
+                self.events.lock().unwrap().push(record);
+            }
+
+            fn enter(&self, id: &span::Id) {
+                self.entered.lock().unwrap().push(id.clone());
+            }
+
+            fn exit(&self, _: &span::Id) {
+                self.entered.lock().unwrap().pop();
+            }
+
+            fn current_span(&self) -> Current {
+                match self.entered.lock().unwrap().last() {
+                    Some(id) => {
+                        let metadata = self.spans.lock().unwrap()[id.into_u64() as usize - 1].0;
+                        Current::new(id.clone(), metadata)
+                    },
+                    None => Current::none(),
+                }
+            }
+        }
+
+        #[test]
+        fn test_verification_emits_tracing_events() {
+            let now = Time::now();
+            let validators = [
+                Validator::new("1").voting_power(50),
+                Validator::new("2").voting_power(50),
+            ];
+            let trusted = light_block(&validators, 1, now.sub(Duration::from_secs(20)).unwrap());
+            let untrusted = light_block(&validators, 3, now.sub(Duration::from_secs(10)).unwrap());
+
+            let capture = Capture::default();
+            let verdict = tracing::subscriber::with_default(capture.clone(), || {
+                ProdVerifier::default().verify_update_header(
+                    untrusted.as_untrusted_state(),
+                    trusted.as_trusted_state(),
+                    &options(),
+                    now,
+                )
+            });
+            assert_eq!(verdict, Verdict::Success);
+
+            let spans: Vec<_> = capture
+                .spans
+                .lock()
+                .unwrap()
+                .drain(..)
+                .map(|s| s.1)
+                .collect();
+            assert_eq!(spans.len(), 1);
+            assert_eq!(spans[0].name, "verify_update_header");
+            assert_eq!(spans[0].field("height"), Some("3"));
+            assert_eq!(spans[0].field("trusted_height"), Some("1"));
+            // The tally stops once the trust threshold of 1/3 is reached
+            assert_eq!(spans[0].field("trust_ratio"), Some("0.5"));
+
+            let events = capture.events.lock().unwrap();
+            let event = |name: &str| {
+                events
+                    .iter()
+                    .find(|e| e.name == name)
+                    .unwrap_or_else(|| panic!("missing event {name:?} in {events:?}"))
+            };
+            assert_eq!(
+                event("trust period check").field("within_trust_period"),
+                Some("true")
+            );
+            assert_eq!(event("commit validation").field("valid"), Some("true"));
+            let tally = event("trusted validators voting power tally");
+            assert_eq!(tally.field("tallied"), Some("50"));
+            assert_eq!(tally.field("total"), Some("100"));
+            assert_eq!(
+                event("commit voting power check").field("sufficient"),
+                Some("true")
+            );
+        }
+    }
+
+    #[test]
+    fn test_verdict_expired_trust() {
+        let now = Time::now();
+        let validators = [Validator::new("1"), Validator::new("2")];
+        let trusted = light_block(&validators, 1, now.sub(Duration::from_secs(120)).unwrap());
+        let untrusted = light_block(&validators, 2, now.sub(Duration::from_secs(10)).unwrap());
+
+        let verdict = ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+
+        match verdict {
+            Verdict::Expired(e) => assert_eq!(e.now, now),
+            v => panic!("expected expired trusted state, got: {v:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verdict_bad_commit() {
+        let now = Time::now();
+        let validators = [Validator::new("1"), Validator::new("2")];
+        let trusted = light_block(&validators, 1, now.sub(Duration::from_secs(20)).unwrap());
+        let mut untrusted = light_block(&validators, 2, now.sub(Duration::from_secs(10)).unwrap());
+        untrusted.signed_header.commit.block_id.hash = Default::default();
+
+        let verdict = ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+
+        match verdict {
+            Verdict::Invalid(e) => {
+                assert!(matches!(e, VerificationErrorDetail::InvalidCommitValue(_)));
+            },
+            v => panic!("expected InvalidCommitValue error, got: {v:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verdict_validator_set_too_different() {
+        let now = Time::now();
+        let trusted = light_block(
+            &[
+                Validator::new("1").voting_power(50),
+                Validator::new("2").voting_power(50),
+            ],
+            1,
+            now.sub(Duration::from_secs(20)).unwrap(),
+        );
+        let untrusted = light_block(
+            &[
+                Validator::new("3").voting_power(50),
+                Validator::new("4").voting_power(50),
+            ],
+            3,
+            now.sub(Duration::from_secs(10)).unwrap(),
+        );
+
+        let verdict = ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+
+        match verdict {
+            Verdict::NotEnoughTrust(tally) => assert_eq!(tally.tallied, 0),
+            v => panic!("expected NotEnoughTrust, got: {v:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verdict_validator_set_rotated_beyond_trust_threshold() {
+        let now = Time::now();
+        let validators = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| Validator::new(name).voting_power(10))
+                .collect::<vec::Vec<_>>()
+        };
+        let trusted = light_block(
+            &validators(&["1", "2", "3", "4"]),
+            1,
+            now.sub(Duration::from_secs(20)).unwrap(),
+        );
+        // Only a quarter of the trusted power is retained, below the 1/3 threshold
+        let mut untrusted = light_block(
+            &validators(&["1", "5", "6", "7"]),
+            3,
+            now.sub(Duration::from_secs(10)).unwrap(),
+        );
+
+        let verdict = ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+        match verdict {
+            Verdict::NotEnoughTrust(tally) => {
+                assert_eq!(tally.total, 40);
+                assert_eq!(tally.tallied, 10);
+            },
+            v => panic!("expected NotEnoughTrust, got: {v:?}"),
+        }
+
+        // Without enough signatures, the commit is invalid against its own
+        // validator set, which is not a matter of trust.
+        for sig in &mut untrusted.signed_header.commit.signatures[2..] {
+            *sig = CommitSig::BlockIdFlagAbsent;
+        }
+        let verdict = ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+        match verdict {
+            Verdict::Invalid(VerificationErrorDetail::InsufficientSignersOverlap(_)) => {},
+            v => panic!("expected InsufficientSignersOverlap, got: {v:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verification_uses_pluggable_hasher() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use tendermint::{crypto::Sha256, merkle::MerkleHash};
+
+        use crate::{
+            operations::{ProdCommitValidator, ProdVotingPowerCalculator},
+            predicates::VerificationPredicates,
+            PredicateVerifier,
+        };
+
+        static INVOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Counts every digest and Merkle node computed with it.
+        #[derive(Default)]
+        struct RecordingHasher(tendermint::crypto::default::Sha256);
+
+        impl Sha256 for RecordingHasher {
+            fn digest(data: impl AsRef<[u8]>) -> [u8; 32] {
+                INVOCATIONS.fetch_add(1, Ordering::SeqCst);
+                tendermint::crypto::default::Sha256::digest(data)
+            }
+        }
+
+        impl MerkleHash for RecordingHasher {
+            fn empty_hash(&mut self) -> [u8; 32] {
+                INVOCATIONS.fetch_add(1, Ordering::SeqCst);
+                self.0.empty_hash()
+            }
+
+            fn leaf_hash(&mut self, bytes: &[u8]) -> [u8; 32] {
+                INVOCATIONS.fetch_add(1, Ordering::SeqCst);
+                self.0.leaf_hash(bytes)
+            }
+
+            fn inner_hash(&mut self, left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+                INVOCATIONS.fetch_add(1, Ordering::SeqCst);
+                self.0.inner_hash(left, right)
+            }
+        }
+
+        struct RecordingPredicates;
+
+        impl VerificationPredicates for RecordingPredicates {
+            type Sha256 = RecordingHasher;
+        }
+
+        let now = Time::now();
+        let testgen_block = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "chain".to_owned(),
+            now.sub(Duration::from_secs(20)).unwrap(),
+            1u64,
+        );
+        let trusted: LightBlock = testgen_block.generate().unwrap().into();
+        let untrusted: LightBlock = testgen_block.next().generate().unwrap().into();
+
+        let verifier = PredicateVerifier::new(
+            RecordingPredicates,
+            ProdVotingPowerCalculator::default(),
+            ProdCommitValidator,
+        );
+        let verdict = verifier.verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &options(),
+            now,
+        );
+
+        assert_eq!(verdict, Verdict::Success);
+        // The validator sets, next validator sets and header of the untrusted
+        // block were all hashed through the recording hasher.
+        assert!(INVOCATIONS.load(Ordering::SeqCst) >= 3);
+    }
 }
@@ -0,0 +1,75 @@
+//! Compares verifying a sequence of light blocks with `verify_single`, which
+//! takes the blocks by value and thus requires cloning them at every step, and
+//! with `verify_single_ref`, which borrows them.
+//!
+//! The allocations saved by borrowing are checked in `tests/allocations.rs`.
+//!
+//! Run with `cargo bench -p tendermint-light-client --bench verify_single`.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use tendermint_light_client::{
+    tests::{verify_single, verify_single_ref},
+    verifier::types::{LightBlock, Time},
+};
+use tendermint_testgen::{Generator, LightChain};
+
+const STEPS: u64 = 100;
+
+fn verify_sequence(c: &mut Criterion) {
+    let blocks: Vec<LightBlock> = LightChain::default_with_length(STEPS + 1)
+        .light_blocks
+        .into_iter()
+        .map(|lb| {
+            let lb = lb.generate().unwrap();
+            LightBlock::new(
+                lb.signed_header,
+                lb.validators,
+                lb.next_validators,
+                lb.provider,
+            )
+        })
+        .collect();
+
+    let trusting_period = Duration::from_secs(3600);
+    let now = Time::from_unix_timestamp(STEPS as i64 + 10, 0).unwrap();
+
+    let mut group = c.benchmark_group("verify_sequence");
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            let mut trusted = blocks[0].clone();
+            for block in &blocks[1..] {
+                trusted = verify_single(
+                    trusted.clone(),
+                    block.clone(),
+                    Default::default(),
+                    trusting_period,
+                    Default::default(),
+                    now,
+                )
+                .unwrap();
+            }
+        })
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            let mut trusted = &blocks[0];
+            for block in &blocks[1..] {
+                trusted = verify_single_ref(
+                    trusted,
+                    block,
+                    Default::default(),
+                    trusting_period,
+                    Default::default(),
+                    now,
+                )
+                .unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, verify_sequence);
+criterion_main!(benches);
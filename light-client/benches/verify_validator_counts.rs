@@ -0,0 +1,106 @@
+//! Measures `verify_single` on adjacent light blocks signed by validator sets
+//! of increasing sizes, along with the signature verification and the hashing
+//! it performs, each in its own benchmark group.
+//!
+//! Run with `cargo bench -p tendermint-light-client --bench verify_validator_counts`.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use tendermint_light_client::{
+    tests::verify_single,
+    verifier::{
+        types::{LightBlock, Time},
+        ProdVerifier, Verdict,
+    },
+};
+use tendermint_testgen::{
+    light_block::LightBlock as TestgenLightBlock, Generator, Header, Validator,
+};
+
+const VALIDATOR_COUNTS: [usize; 3] = [4, 100, 1000];
+
+fn light_blocks(count: usize) -> (LightBlock, LightBlock) {
+    let validators = (0..count)
+        .map(|i| Validator::new(&i.to_string()).voting_power(50))
+        .collect::<Vec<_>>();
+    let header = Header::new(&validators)
+        .height(1)
+        .chain_id("bench-chain")
+        .next_validators(&validators)
+        .time(Time::from_unix_timestamp(1, 0).unwrap());
+
+    let testgen_block = TestgenLightBlock::new_default_with_header(header);
+    let to_lb = |testgen_block: TestgenLightBlock| {
+        let lb = testgen_block.generate().unwrap();
+        LightBlock::new(
+            lb.signed_header,
+            lb.validators,
+            lb.next_validators,
+            lb.provider,
+        )
+    };
+    (to_lb(testgen_block.clone()), to_lb(testgen_block.next()))
+}
+
+fn verify_validator_counts(c: &mut Criterion) {
+    let blocks = VALIDATOR_COUNTS.map(|count| (count, light_blocks(count)));
+
+    let trusting_period = Duration::from_secs(3600);
+    let now = Time::from_unix_timestamp(10, 0).unwrap();
+    let mut group = c.benchmark_group("verify_single");
+    for (count, (trusted, untrusted)) in &blocks {
+        group.bench_with_input(BenchmarkId::from_parameter(count), count, |b, _| {
+            b.iter(|| {
+                verify_single(
+                    trusted.clone(),
+                    untrusted.clone(),
+                    Default::default(),
+                    trusting_period,
+                    Default::default(),
+                    now,
+                )
+                .unwrap()
+            })
+        });
+    }
+    group.finish();
+
+    let vp = ProdVerifier::default();
+    let mut group = c.benchmark_group("signatures");
+    for (count, (_, untrusted)) in &blocks {
+        group.bench_with_input(BenchmarkId::from_parameter(count), count, |b, _| {
+            b.iter(|| {
+                assert_eq!(
+                    vp.verify_commit(&untrusted.as_untrusted_state()),
+                    Verdict::Success
+                )
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("hashing");
+    for (count, (_, untrusted)) in &blocks {
+        group.bench_with_input(BenchmarkId::from_parameter(count), count, |b, _| {
+            b.iter(|| {
+                assert_eq!(
+                    untrusted.validators.hash(),
+                    untrusted.signed_header.header.validators_hash
+                );
+                assert_eq!(
+                    untrusted.next_validators.hash(),
+                    untrusted.signed_header.header.next_validators_hash
+                );
+                assert_eq!(
+                    untrusted.signed_header.header.hash(),
+                    untrusted.signed_header.commit.block_id.hash
+                );
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, verify_validator_counts);
+criterion_main!(benches);
@@ -149,6 +149,29 @@ where
         self.trust_light_block(trusted_state)
     }
 
+    /// Resume from the latest verified or trusted block in the light store if
+    /// it is still within the trusting period, otherwise bootstrap from the
+    /// block of the primary peer at the given height, as with
+    /// [`trust_primary_at`](Self::trust_primary_at).
+    ///
+    /// This lets a light client backed by a persistent store pick up where it
+    /// left off on restart, instead of verifying again from the trust anchor.
+    pub fn trust_from_store_or_primary_at(
+        self,
+        trusted_height: Height,
+        trusted_hash: Hash,
+    ) -> Result<LightClientBuilder<HasTrustedState, H>, Error> {
+        let stored_state = self
+            .light_store
+            .highest_trusted_or_verified()
+            .filter(|light_block| self.validate(light_block).is_ok());
+
+        match stored_state {
+            Some(trusted_state) => self.trust_light_block(trusted_state),
+            None => self.trust_primary_at(trusted_height, trusted_hash),
+        }
+    }
+
     /// Set the block from the primary peer at the given height as the trusted state.
     pub fn trust_primary_at(
         self,
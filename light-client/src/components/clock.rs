@@ -21,12 +21,17 @@ impl Clock for SystemClock {
     }
 }
 
+/// A clock which always reports the same, fixed time.
+///
+/// Useful for deterministically driving time-dependent checks, such as
+/// the trusting period, in tests.
 #[derive(Copy, Clone, Debug)]
 pub struct FixedClock {
     now: Time,
 }
 
 impl FixedClock {
+    /// Constructs a clock which always reports `now` as the current time.
     pub fn new(now: Time) -> Self {
         Self { now }
     }
@@ -2,6 +2,7 @@
 
 use std::time::Duration;
 
+use async_trait::async_trait;
 use flex_error::{define_error, TraceError};
 use tendermint_rpc as rpc;
 #[cfg(feature = "rpc-client")]
@@ -111,6 +112,77 @@ impl IoErrorDetail {
     }
 }
 
+impl IoError {
+    /// Whether this error is transient, ie. whether retrying the same
+    /// request may succeed, as opposed to definitive errors such as a
+    /// `404 Not Found` or a malformed response.
+    pub fn is_transient(&self) -> bool {
+        match self.detail() {
+            IoErrorDetail::Timeout(_) => true,
+            IoErrorDetail::Rpc(e) => match &e.source {
+                rpc::error::ErrorDetail::Io(_)
+                | rpc::error::ErrorDetail::Http(_)
+                | rpc::error::ErrorDetail::Timeout(_)
+                | rpc::error::ErrorDetail::WebSocketTimeout(_) => true,
+                rpc::error::ErrorDetail::HttpRequestFailed(e) => {
+                    let status = u16::from(e.status);
+                    status == 429 || (500..600).contains(&status)
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Policy for retrying idempotent requests which failed with a
+/// [transient](IoError::is_transient) error.
+///
+/// The delay before the n-th retry is `base_delay * 2^(n - 1)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Constructs a new retry policy.
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+        }
+    }
+
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    /// Runs `f` until it succeeds, fails with an error which is not
+    /// transient, or the maximum number of attempts is reached.
+    pub fn retry<T>(&self, mut f: impl FnMut() -> Result<T, IoError>) -> Result<T, IoError> {
+        let mut attempt = 1;
+        loop {
+            match f() {
+                Err(e) if e.is_transient() && attempt < self.max_attempts => {
+                    std::thread::sleep(self.base_delay.saturating_mul(1 << (attempt - 1).min(31)));
+                    attempt += 1;
+                },
+                res => return res,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(100))
+    }
+}
+
 /// Interface for fetching light blocks from a full node, typically via the RPC client.
 pub trait Io: Send + Sync {
     /// Fetch a light block at the given height from a peer
@@ -126,11 +198,24 @@ where
     }
 }
 
+/// Interface for fetching light blocks from a full node asynchronously,
+/// typically via the async RPC client, for use within async runtimes.
+#[async_trait]
+pub trait AsyncIo: Send + Sync {
+    /// Fetch a light block at the given height from a peer
+    async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError>;
+}
+
 #[cfg(feature = "rpc-client")]
 pub use self::prod::ProdIo;
 
+#[cfg(feature = "rpc-client")]
+pub use self::prod_async::AsyncProdIo;
+
 #[cfg(feature = "rpc-client")]
 mod prod {
+    use std::sync::{Arc, Mutex};
+
     use tendermint::{
         account::Id as TMAccountId, block::signed_header::SignedHeader as TMSignedHeader,
         validator::Set as TMValidatorSet,
@@ -147,6 +232,12 @@ mod prod {
         peer_id: PeerId,
         rpc_client: rpc::HttpClient,
         timeout: Option<Duration>,
+        retry_policy: RetryPolicy,
+        // The next validator set of the last fetched light block, which is
+        // reused as the validator set of the following block when their
+        // hashes match, sparing a `/validators` request when syncing
+        // sequentially.
+        last_next_validator_set: Arc<Mutex<Option<TMValidatorSet>>>,
     }
 
     impl Io for ProdIo {
@@ -155,9 +246,24 @@ mod prod {
             let height = signed_header.header.height;
             let proposer_address = signed_header.header.proposer_address;
 
-            let validator_set = self.fetch_validator_set(height.into(), Some(proposer_address))?;
+            let cached_validator_set = self
+                .last_next_validator_set
+                .lock()
+                .unwrap()
+                .take()
+                .filter(|vals| vals.hash() == signed_header.header.validators_hash);
+
+            let validator_set = match cached_validator_set {
+                Some(vals) => {
+                    TMValidatorSet::with_proposer(vals.validators().clone(), proposer_address)
+                        .map_err(IoError::invalid_validator_set)?
+                },
+                None => self.fetch_validator_set(height.into(), Some(proposer_address))?,
+            };
             let next_validator_set = self.fetch_validator_set(height.increment().into(), None)?;
 
+            *self.last_next_validator_set.lock().unwrap() = Some(next_validator_set.clone());
+
             let light_block = LightBlock::new(
                 signed_header,
                 validator_set,
@@ -173,6 +279,9 @@ mod prod {
         /// Constructs a new ProdIo component.
         ///
         /// A peer map which maps peer IDS to their network address must be supplied.
+        ///
+        /// Requests are attempted only once, see [`ProdIo::with_retry_policy`]
+        /// to retry those failing with a transient error.
         pub fn new(
             peer_id: PeerId,
             rpc_client: rpc::HttpClient, /* TODO(thane): Generalize over client transport
@@ -183,9 +292,18 @@ mod prod {
                 peer_id,
                 rpc_client,
                 timeout,
+                retry_policy: RetryPolicy::none(),
+                last_next_validator_set: Arc::new(Mutex::new(None)),
             }
         }
 
+        /// Retry the `/commit` and `/validators` requests which fail with a
+        /// transient error according to the given policy.
+        pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+            self.retry_policy = retry_policy;
+            self
+        }
+
         pub fn peer_id(&self) -> PeerId {
             self.peer_id
         }
@@ -198,22 +316,406 @@ mod prod {
             self.timeout
         }
 
+        pub fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+
         pub fn fetch_signed_header(&self, height: AtHeight) -> Result<TMSignedHeader, IoError> {
-            let client = self.rpc_client.clone();
-            let res = block_on(self.timeout, async move {
-                match height {
-                    AtHeight::Highest => client.latest_commit().await,
-                    AtHeight::At(height) => client.commit(height).await,
+            let height = match height {
+                AtHeight::Highest => None,
+                AtHeight::At(height) => Some(height),
+            };
+
+            self.retry_policy.retry(|| {
+                let client = self.rpc_client.clone();
+                let res = block_on(self.timeout, async move {
+                    match height {
+                        None => client.latest_commit().await,
+                        Some(height) => client.commit(height).await,
+                    }
+                })?;
+
+                match res {
+                    Ok(response) => Ok(response.signed_header),
+                    Err(err) => Err(IoError::from_rpc(err)),
                 }
+            })
+        }
+
+        /// Fetch the status of the node, retrying on transient errors.
+        pub fn fetch_status(&self) -> Result<rpc::endpoint::status::Response, IoError> {
+            self.retry_policy.retry(|| {
+                let client = self.rpc_client.clone();
+                block_on(self.timeout, async move { client.status().await })?.map_err(IoError::rpc)
+            })
+        }
+
+        pub fn fetch_validator_set(
+            &self,
+            height: AtHeight,
+            proposer_address: Option<TMAccountId>,
+        ) -> Result<TMValidatorSet, IoError> {
+            let height = match height {
+                AtHeight::Highest => {
+                    return Err(IoError::invalid_height());
+                },
+                AtHeight::At(height) => height,
+            };
+
+            let response = self.retry_policy.retry(|| {
+                let client = self.rpc_client.clone();
+                block_on(self.timeout, async move {
+                    client.validators(height, Paging::All).await
+                })?
+                .map_err(IoError::rpc)
             })?;
 
-            match res {
-                Ok(response) => Ok(response.signed_header),
-                Err(err) => Err(IoError::from_rpc(err)),
+            let validator_set = match proposer_address {
+                Some(proposer_address) => {
+                    TMValidatorSet::with_proposer(response.validators, proposer_address)
+                        .map_err(IoError::invalid_validator_set)?
+                },
+                None => TMValidatorSet::without_proposer(response.validators),
+            };
+
+            Ok(validator_set)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::{
+            io::{BufRead, BufReader, Read, Write},
+            net::{TcpListener, TcpStream},
+            thread,
+        };
+
+        use tendermint_rpc::endpoint::{commit, validators};
+        use tendermint_testgen::{light_block::TmLightBlock, Generator, LightChain};
+
+        use super::*;
+
+        /// Reads the JSON-RPC request sent over the given connection.
+        fn read_request(stream: &TcpStream) -> serde_json::Value {
+            let mut reader = BufReader::new(stream);
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
             }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            serde_json::from_slice(&body).unwrap()
         }
 
-        pub fn fetch_validator_set(
+        /// Serves the `/commit` and `/validators` endpoints for the given
+        /// chain over HTTP, recording the height of every validator set
+        /// requested.
+        fn serve_chain(chain: Vec<TmLightBlock>) -> (String, Arc<Mutex<Vec<u64>>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let validators_requests = Arc::new(Mutex::new(Vec::new()));
+
+            let requests = validators_requests.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&stream);
+                    let height: u64 = request["params"]["height"]
+                        .as_str()
+                        .unwrap()
+                        .parse()
+                        .unwrap();
+                    let block = &chain[height as usize - 1];
+                    let result = match request["method"].as_str().unwrap() {
+                        "commit" => serde_json::to_value(commit::Response {
+                            signed_header: block.signed_header.clone(),
+                            canonical: true,
+                        }),
+                        "validators" => {
+                            requests.lock().unwrap().push(height);
+                            let vals = block.validators.validators().clone();
+                            let total = vals.len() as i32;
+                            serde_json::to_value(validators::Response::new(
+                                height.try_into().unwrap(),
+                                vals,
+                                total,
+                            ))
+                        },
+                        method => panic!("unexpected method: {method}"),
+                    }
+                    .unwrap();
+
+                    let response = serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": result,
+                    })
+                    .to_string();
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                         content-length: {}\r\nconnection: close\r\n\r\n{}",
+                        response.len(),
+                        response
+                    )
+                    .unwrap();
+                }
+            });
+
+            (url, validators_requests)
+        }
+
+        #[test]
+        fn sequential_fetches_reuse_next_validator_set() {
+            let chain: Vec<TmLightBlock> = LightChain::default_with_length(4)
+                .light_blocks
+                .into_iter()
+                .map(|lb| lb.generate().unwrap())
+                .collect();
+            let (url, validators_requests) = serve_chain(chain.clone());
+
+            let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+            let io = ProdIo::new(chain[0].provider, rpc_client, None);
+
+            for expected in &chain[..3] {
+                let height = expected.signed_header.header.height;
+                let light_block = io.fetch_light_block(AtHeight::At(height)).unwrap();
+
+                assert_eq!(light_block.signed_header, expected.signed_header);
+                assert_eq!(light_block.validators.hash(), expected.validators.hash());
+                assert_eq!(
+                    light_block
+                        .validators
+                        .proposer()
+                        .as_ref()
+                        .map(|p| p.address),
+                    Some(expected.signed_header.header.proposer_address)
+                );
+            }
+
+            // Only the first light block required its validator set to be
+            // fetched, the following ones reused the previous next validator set.
+            assert_eq!(*validators_requests.lock().unwrap(), vec![1, 2, 3, 4]);
+        }
+
+        /// Serves the `/commit` endpoint for the given light block over
+        /// HTTP, answering with the given HTTP statuses first, and returns
+        /// the number of requests received.
+        fn serve_flaky_commit(
+            block: TmLightBlock,
+            failures: Vec<u16>,
+        ) -> (String, Arc<Mutex<usize>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let attempts = Arc::new(Mutex::new(0));
+
+            let counter = attempts.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&stream);
+
+                    let attempt = {
+                        let mut counter = counter.lock().unwrap();
+                        *counter += 1;
+                        *counter
+                    };
+
+                    let response = match failures.get(attempt - 1) {
+                        Some(status) => format!(
+                            "HTTP/1.1 {status} Failure\r\ncontent-length: 0\r\n\
+                             connection: close\r\n\r\n"
+                        ),
+                        None => {
+                            let body = serde_json::json!({
+                                "jsonrpc": "2.0",
+                                "id": request["id"],
+                                "result": commit::Response {
+                                    signed_header: block.signed_header.clone(),
+                                    canonical: true,
+                                },
+                            })
+                            .to_string();
+                            format!(
+                                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                                 content-length: {}\r\nconnection: close\r\n\r\n{}",
+                                body.len(),
+                                body
+                            )
+                        },
+                    };
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+
+            (url, attempts)
+        }
+
+        #[test]
+        fn transient_errors_are_retried() {
+            let block = LightChain::default_with_length(1).light_blocks[0]
+                .generate()
+                .unwrap();
+            let (url, attempts) = serve_flaky_commit(block.clone(), vec![503, 503]);
+
+            let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+            let io = ProdIo::new(block.provider, rpc_client, None)
+                .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1)));
+
+            let height = block.signed_header.header.height;
+            let signed_header = io.fetch_signed_header(AtHeight::At(height)).unwrap();
+
+            assert_eq!(signed_header, block.signed_header);
+            assert_eq!(*attempts.lock().unwrap(), 3);
+        }
+
+        #[test]
+        fn definitive_errors_are_not_retried() {
+            let block = LightChain::default_with_length(1).light_blocks[0]
+                .generate()
+                .unwrap();
+            let (url, attempts) = serve_flaky_commit(block.clone(), vec![404, 404]);
+
+            let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+            let io = ProdIo::new(block.provider, rpc_client, None)
+                .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1)));
+
+            let height = block.signed_header.header.height;
+            let err = io.fetch_signed_header(AtHeight::At(height)).unwrap_err();
+
+            assert!(!err.is_transient());
+            assert_eq!(*attempts.lock().unwrap(), 1);
+        }
+
+        #[test]
+        fn requests_are_not_retried_by_default() {
+            let block = LightChain::default_with_length(1).light_blocks[0]
+                .generate()
+                .unwrap();
+            let (url, attempts) = serve_flaky_commit(block.clone(), vec![503]);
+
+            let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+            let io = ProdIo::new(block.provider, rpc_client, None);
+
+            let height = block.signed_header.header.height;
+            let err = io.fetch_signed_header(AtHeight::At(height)).unwrap_err();
+
+            assert!(err.is_transient());
+            assert_eq!(*attempts.lock().unwrap(), 1);
+        }
+
+        #[test]
+        fn retries_are_bounded() {
+            let block = LightChain::default_with_length(1).light_blocks[0]
+                .generate()
+                .unwrap();
+            let (url, attempts) = serve_flaky_commit(block.clone(), vec![503, 503]);
+
+            let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+            let io = ProdIo::new(block.provider, rpc_client, None)
+                .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+
+            let height = block.signed_header.header.height;
+            let err = io.fetch_signed_header(AtHeight::At(height)).unwrap_err();
+
+            assert!(err.is_transient());
+            assert_eq!(*attempts.lock().unwrap(), 2);
+        }
+    }
+}
+
+#[cfg(feature = "rpc-client")]
+mod prod_async {
+    use tendermint::{
+        account::Id as TMAccountId, block::signed_header::SignedHeader as TMSignedHeader,
+        validator::Set as TMValidatorSet,
+    };
+    use tendermint_rpc::Paging;
+
+    use super::*;
+    use crate::verifier::types::PeerId;
+
+    /// Implementation of the [`AsyncIo`] component over any async RPC
+    /// client, such as the HTTP or WebSocket clients, which fetches light
+    /// blocks by composing the `/commit` and `/validators` endpoints.
+    #[derive(Clone, Debug)]
+    pub struct AsyncProdIo<C> {
+        peer_id: PeerId,
+        rpc_client: C,
+    }
+
+    #[async_trait]
+    impl<C> AsyncIo for AsyncProdIo<C>
+    where
+        C: Client + Send + Sync,
+    {
+        async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+            let signed_header = self.fetch_signed_header(height).await?;
+            let height = signed_header.header.height;
+            let proposer_address = signed_header.header.proposer_address;
+
+            let validator_set = self
+                .fetch_validator_set(height.into(), Some(proposer_address))
+                .await?;
+            let next_validator_set = self
+                .fetch_validator_set(height.increment().into(), None)
+                .await?;
+
+            Ok(LightBlock::new(
+                signed_header,
+                validator_set,
+                next_validator_set,
+                self.peer_id,
+            ))
+        }
+    }
+
+    impl<C> AsyncProdIo<C>
+    where
+        C: Client + Send + Sync,
+    {
+        /// Constructs a new AsyncProdIo component.
+        pub fn new(peer_id: PeerId, rpc_client: C) -> Self {
+            Self {
+                peer_id,
+                rpc_client,
+            }
+        }
+
+        pub fn peer_id(&self) -> PeerId {
+            self.peer_id
+        }
+
+        pub fn rpc_client(&self) -> &C {
+            &self.rpc_client
+        }
+
+        pub async fn fetch_signed_header(
+            &self,
+            height: AtHeight,
+        ) -> Result<TMSignedHeader, IoError> {
+            let response = match height {
+                AtHeight::Highest => self.rpc_client.latest_commit().await,
+                AtHeight::At(height) => self.rpc_client.commit(height).await,
+            };
+
+            response
+                .map(|response| response.signed_header)
+                .map_err(IoError::from_rpc)
+        }
+
+        pub async fn fetch_validator_set(
             &self,
             height: AtHeight,
             proposer_address: Option<TMAccountId>,
@@ -225,11 +727,11 @@ mod prod {
                 AtHeight::At(height) => height,
             };
 
-            let client = self.rpc_client.clone();
-            let response = block_on(self.timeout, async move {
-                client.validators(height, Paging::All).await
-            })?
-            .map_err(IoError::rpc)?;
+            let response = self
+                .rpc_client
+                .validators(height, Paging::All)
+                .await
+                .map_err(IoError::rpc)?;
 
             let validator_set = match proposer_address {
                 Some(proposer_address) => {
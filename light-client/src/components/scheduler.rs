@@ -1,8 +1,12 @@
 //! Provides an interface and default implementation of the `Scheduler` component
 
 use contracts::*;
+use tendermint::trust_threshold::{TrustThreshold, TrustThresholdFraction};
 
-use crate::{store::LightStore, verifier::types::Height};
+use crate::{
+    store::LightStore,
+    verifier::types::{Height, ValidatorSet},
+};
 
 /// The scheduler decides what block to verify next given the current and target heights.
 ///
@@ -73,6 +77,115 @@ pub fn basic_bisecting_schedule(
     }
 }
 
+/// Bisecting scheduler which, when the block at the current height cannot be
+/// verified, picks the next height based on how many of the trusted
+/// validators remain in the validator set of that block.
+///
+/// The voting power of the trusted validators is assumed to leave the
+/// validator set at a constant rate between the trusted and the current
+/// heights, and the scheduler picks the height at which a little more than
+/// `trust_threshold` of it is expected to remain. The more overlap there is
+/// with the current block, the closer to it that height is, which saves round
+/// trips on chains whose validator set changes slowly.
+///
+/// Falls back to the midpoint picked by [`basic_bisecting_schedule`] when
+/// the block at the current height is not in the light store, or shares no
+/// validator with the trusted block.
+#[derive(Clone, Copy, Debug)]
+pub struct OverlapScheduler<T = TrustThresholdFraction> {
+    /// The trust threshold used to verify blocks.
+    pub trust_threshold: T,
+}
+
+impl<T> OverlapScheduler<T> {
+    /// Constructor.
+    pub fn new(trust_threshold: T) -> Self {
+        Self { trust_threshold }
+    }
+}
+
+impl Default for OverlapScheduler {
+    fn default() -> Self {
+        Self::new(TrustThresholdFraction::default())
+    }
+}
+
+#[contract_trait]
+impl<T: TrustThreshold + Send + Sync> Scheduler for OverlapScheduler<T> {
+    fn schedule(
+        &self,
+        light_store: &dyn LightStore,
+        current_height: Height,
+        target_height: Height,
+    ) -> Height {
+        let trusted_block = light_store
+            .highest_trusted_or_verified_before(target_height)
+            .unwrap();
+        let trusted_height = trusted_block.height();
+
+        if trusted_height == current_height {
+            return target_height;
+        }
+
+        let total = trusted_block.next_validators.total_voting_power().value();
+        let remaining = light_store
+            .get_non_failed(current_height)
+            .map(|(current_block, _)| {
+                remaining_power(&trusted_block.next_validators, &current_block.validators)
+            })
+            .unwrap_or(0);
+        let distance = current_height.value() - trusted_height.value();
+
+        if remaining == 0 || self.trust_threshold.is_enough_power(remaining, total) || distance < 2
+        {
+            return midpoint(trusted_height, current_height);
+        }
+
+        let overlap = remaining as f64 / total as f64;
+        let threshold = least_enough_power(&self.trust_threshold, total)
+            .map_or(1.0, |power| power as f64 / total as f64);
+
+        // Aim slightly above the threshold, to leave some margin for the
+        // validator set not changing at a constant rate.
+        let goal = threshold + (1.0 - threshold) / 10.0;
+        let skip = (distance as f64 * (1.0 - goal) / (1.0 - overlap)) as u64;
+
+        (trusted_height.value() + skip.clamp(1, distance - 1))
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// The voting power of `trusted` held by validators which are also in
+/// `untrusted`.
+fn remaining_power(trusted: &ValidatorSet, untrusted: &ValidatorSet) -> u64 {
+    trusted
+        .iter()
+        .filter(|v| untrusted.contains(&v.address))
+        .map(|v| v.power())
+        .sum()
+}
+
+/// The least voting power which is enough out of `total` according to
+/// `trust_threshold`, if even `total` is.
+fn least_enough_power(trust_threshold: &impl TrustThreshold, total: u64) -> Option<u64> {
+    if !trust_threshold.is_enough_power(total, total) {
+        return None;
+    }
+
+    // Enough power is monotonic in the signed voting power.
+    let (mut low, mut high) = (0, total);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if trust_threshold.is_enough_power(mid, total) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Some(high)
+}
+
 /// Checks whether the given `scheduled_height` is a valid schedule according to the
 /// following specification.
 ///
@@ -125,3 +238,222 @@ fn midpoint(low: Height, high: Height) -> Height {
         .try_into()
         .unwrap() // Will panic if midpoint is higher than i64::MAX
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use tendermint_testgen::{
+        light_block::TmLightBlock, Generator, LightBlock as TestgenLightBlock, Validator,
+    };
+
+    use tendermint::trust_threshold::AbsolutePower;
+
+    use super::*;
+    use crate::{
+        store::memory::MemoryStore,
+        verifier::types::{LightBlock, Status, ValidatorSet},
+    };
+
+    fn light_block(height: u64) -> LightBlock {
+        let tm_lb: TmLightBlock = TestgenLightBlock::new_default(height).generate().unwrap();
+        LightBlock {
+            signed_header: tm_lb.signed_header,
+            validators: tm_lb.validators,
+            next_validators: tm_lb.next_validators,
+            provider: tm_lb.provider,
+        }
+    }
+
+    fn height(h: u64) -> Height {
+        h.try_into().unwrap()
+    }
+
+    #[test]
+    fn bisects_down_to_adjacency() {
+        let mut store = MemoryStore::new();
+        store.insert(light_block(1), Status::Trusted);
+
+        let target = height(1000);
+        let mut current = target;
+        let mut schedule = vec![];
+
+        // No block above the trusted one can be verified until it is adjacent
+        while current.value() > 2 {
+            current = basic_bisecting_schedule(&store, current, target);
+            schedule.push(current.value());
+        }
+
+        assert_eq!(schedule, vec![501, 251, 126, 64, 33, 17, 9, 5, 3, 2]);
+    }
+
+    #[test]
+    fn schedules_target_once_current_is_trusted() {
+        let mut store = MemoryStore::new();
+        store.insert(light_block(1), Status::Trusted);
+        store.insert(light_block(2), Status::Verified);
+
+        let target = height(1000);
+        assert_eq!(basic_bisecting_schedule(&store, height(2), target), target);
+    }
+
+    #[test]
+    fn converges_to_target() {
+        // A block can only be verified if it is at most `MAX_GAP` blocks
+        // above the highest trusted or verified block.
+        const MAX_GAP: u64 = 8;
+
+        let mut store = MemoryStore::new();
+        store.insert(light_block(1), Status::Trusted);
+
+        let target = height(100);
+        let mut current = target;
+        let mut scheduled = BTreeSet::new();
+
+        for _ in 0..1000 {
+            let trusted = store.highest_trusted_or_verified().unwrap().height();
+            if trusted == target {
+                break;
+            }
+
+            if current.value() - trusted.value() <= MAX_GAP {
+                store.insert(light_block(current.value()), Status::Verified);
+                // Progress was made, heights may be scheduled again
+                scheduled.clear();
+            }
+
+            current = basic_bisecting_schedule(&store, current, target);
+            assert!(
+                scheduled.insert(current),
+                "height {current} scheduled twice without progress"
+            );
+        }
+
+        assert_eq!(
+            store.highest_trusted_or_verified().unwrap().height(),
+            target
+        );
+    }
+
+    /// Counts the blocks fetched to reach `target` from a trusted block at
+    /// height 1, on a chain whose validator set of `size` validators has one
+    /// validator replaced every `rotation` blocks. A block can be verified if
+    /// enough of the trusted validators according to `trust_threshold` are in
+    /// its validator set.
+    fn count_round_trips(
+        scheduler: &dyn Scheduler,
+        trust_threshold: impl TrustThreshold,
+        size: u64,
+        rotation: u64,
+        target: u64,
+    ) -> usize {
+        let validators = |height: u64| {
+            let first = height / rotation;
+            ValidatorSet::without_proposer(
+                (first..first + size)
+                    .map(|i| {
+                        Validator::new(&i.to_string())
+                            .voting_power(10)
+                            .generate()
+                            .unwrap()
+                    })
+                    .collect(),
+            )
+        };
+        let light_block = |height: u64| {
+            let mut lb = light_block(height);
+            lb.validators = validators(height);
+            lb.next_validators = validators(height + 1);
+            lb
+        };
+
+        let mut store = MemoryStore::new();
+        store.insert(light_block(1), Status::Trusted);
+
+        let target = height(target);
+        let mut current = target;
+        let mut round_trips = 0;
+
+        while store.highest_trusted_or_verified().unwrap().height() != target {
+            let trusted = store.highest_trusted_or_verified().unwrap();
+            let current_block = light_block(current.value());
+            round_trips += 1;
+
+            let remaining = remaining_power(&trusted.next_validators, &current_block.validators);
+            let total = trusted.next_validators.total_voting_power().value();
+            if trust_threshold.is_enough_power(remaining, total) {
+                store.insert(current_block, Status::Verified);
+            } else {
+                store.insert(current_block, Status::Unverified);
+            }
+
+            current = scheduler.schedule(&store, current, target);
+        }
+
+        round_trips
+    }
+
+    #[test]
+    fn overlap_scheduler_saves_round_trips() {
+        let one_third = TrustThresholdFraction::ONE_THIRD;
+        let basic = count_round_trips(&basic_bisecting_schedule, one_third, 30, 10, 1000);
+        let overlap = count_round_trips(&OverlapScheduler::default(), one_third, 30, 10, 1000);
+
+        assert!(
+            overlap < basic,
+            "{overlap} round trips with the overlap scheduler, {basic} with bisection"
+        );
+    }
+
+    #[test]
+    fn overlap_scheduler_with_absolute_power() {
+        // Out of the 300 voting power of the trusted validators, as much as
+        // with a third of it
+        let absolute = AbsolutePower::new(101).unwrap();
+        let basic = count_round_trips(&basic_bisecting_schedule, absolute, 30, 10, 1000);
+        let overlap = count_round_trips(&OverlapScheduler::new(absolute), absolute, 30, 10, 1000);
+
+        assert!(
+            overlap < basic,
+            "{overlap} round trips with the overlap scheduler, {basic} with bisection"
+        );
+        assert_eq!(
+            overlap,
+            count_round_trips(
+                &OverlapScheduler::default(),
+                TrustThresholdFraction::ONE_THIRD,
+                30,
+                10,
+                1000
+            )
+        );
+    }
+
+    #[test]
+    fn least_enough_power_matches_trust_threshold() {
+        let one_third = TrustThresholdFraction::ONE_THIRD;
+        assert_eq!(least_enough_power(&one_third, 300), Some(101));
+        assert_eq!(least_enough_power(&one_third, 0), None);
+
+        let absolute = AbsolutePower::new(50).unwrap();
+        assert_eq!(least_enough_power(&absolute, 300), Some(50));
+        assert_eq!(least_enough_power(&absolute, 49), None);
+    }
+
+    #[test]
+    fn overlap_scheduler_falls_back_to_midpoint() {
+        let mut store = MemoryStore::new();
+        store.insert(light_block(1), Status::Trusted);
+
+        // The block at the current height was not fetched
+        let scheduler = OverlapScheduler::default();
+        assert_eq!(
+            scheduler.schedule(&store, height(1000), height(1000)),
+            basic_bisecting_schedule(&store, height(1000), height(1000))
+        );
+        assert_eq!(
+            scheduler.schedule(&store, height(1), height(1000)),
+            height(1000)
+        );
+    }
+}
@@ -95,10 +95,40 @@ define_error! {
                     e.target_height, e.trusted_height)
             },
 
+        BisectionDepthExceeded
+            {
+                target_height: Height,
+                max_depth: usize,
+            }
+            | e | {
+                format_args!("bisection for target at height {0} exceeded the maximum depth of {1} without establishing trust",
+                    e.target_height, e.max_depth)
+            },
+
         InvalidLightBlock
             [ DisplayError<VerificationErrorDetail> ]
             | _ | { "invalid light block" },
 
+        TrustedHeightMismatch
+            {
+                given: Height,
+                found: Height,
+            }
+            | e | {
+                format_args!("trusted height mismatch: given = {0}, found = {1}",
+                    e.given, e.found)
+            },
+
+        TrustedHashMismatch
+            {
+                given: Hash,
+                found: Hash,
+            }
+            | e | {
+                format_args!("trusted hash mismatch: given = {0}, found = {1}",
+                    e.given, e.found)
+            },
+
         InvalidAdjacentHeaders
             {
                 hash1: Hash,
@@ -174,3 +204,37 @@ impl Error {
         Error::channel_disconnected()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+    use crate::{components::io::IoErrorDetail, verifier::errors::VerificationError};
+
+    fn assert_std_error<E: std::error::Error + Send + Sync + 'static>() {}
+
+    #[test]
+    fn errors_are_send_sync_std_errors() {
+        assert_std_error::<Error>();
+        assert_std_error::<IoError>();
+        assert_std_error::<VerificationError>();
+    }
+
+    #[test]
+    fn io_error_is_reported_as_source() {
+        let err = Error::io(IoError::runtime(std::io::Error::other("boom")));
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::Io(e) if matches!(e.source, IoErrorDetail::Runtime(_))
+        ));
+
+        let sources: Vec<_> = std::iter::successors(err.source(), |&e| e.source())
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            sources,
+            ["io error", "failed to initialize runtime", "boom"]
+        );
+    }
+}
@@ -21,6 +21,7 @@ pub mod contracts;
 pub mod errors;
 pub mod instance;
 pub mod light_client;
+pub mod peer_list;
 pub mod state;
 pub mod store;
 
@@ -8,17 +8,56 @@ use contracts::*;
 
 // Re-export for backward compatibility
 pub use crate::verifier::options::Options;
+#[cfg(feature = "rust-crypto")]
+use crate::verifier::{
+    predicates::{ProdPredicates, VerificationPredicates},
+    types::Hash,
+};
 use crate::{
     components::{clock::Clock, io::*, scheduler::*},
     contracts::*,
     errors::Error,
     state::State,
     verifier::{
-        types::{Height, LightBlock, PeerId, Status},
+        errors::VerificationErrorDetail,
+        types::{Height, LightBlock, PeerId, Status, TrustedState},
         Verdict, Verifier,
     },
 };
 
+/// Default maximum number of consecutive bisection steps a light client performs
+/// without establishing trust in a new light block.
+pub const DEFAULT_MAX_BISECTION_DEPTH: usize = 50;
+
+/// How [`LightClient::verify_batch`] proceeds once a light block fails verification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchMode {
+    /// Stop at the first light block which cannot be trusted
+    AbortOnFirstFailure,
+    /// Verify every light block of the batch, against the latest trusted one
+    CollectAll,
+}
+
+/// The outcome of verifying a batch of light blocks with [`LightClient::verify_batch`].
+#[derive(Debug)]
+pub struct BatchResult {
+    /// The verdict for each light block verified, in the order of the batch.
+    ///
+    /// When aborting on the first failure, the last verdict is that failure,
+    /// and the light blocks after it have no verdict.
+    pub verdicts: Vec<Verdict>,
+    /// The latest light block of the batch which was trusted, or the initial
+    /// trusted state if there is none
+    pub trusted: TrustedState,
+}
+
+impl BatchResult {
+    /// Whether every light block of the batch was verified successfully
+    pub fn is_success(&self) -> bool {
+        self.verdicts.iter().all(|v| *v == Verdict::Success)
+    }
+}
+
 /// The light client implements a read operation of a header from the blockchain,
 /// by communicating with full nodes. As full nodes may be faulty, it cannot trust
 /// the received information, but the light client has to check whether the header
@@ -34,6 +73,9 @@ pub struct LightClient {
     pub peer: PeerId,
     /// Options for this light client
     pub options: Options,
+    /// Maximum number of consecutive bisection steps performed without
+    /// establishing trust in a new light block
+    pub max_bisection_depth: usize,
 
     clock: Box<dyn Clock>,
     scheduler: Box<dyn Scheduler>,
@@ -46,6 +88,7 @@ impl fmt::Debug for LightClient {
         f.debug_struct("LightClient")
             .field("peer", &self.peer)
             .field("options", &self.options)
+            .field("max_bisection_depth", &self.max_bisection_depth)
             .finish()
     }
 }
@@ -63,6 +106,7 @@ impl LightClient {
         Self {
             peer,
             options,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
             clock: Box::new(clock),
             scheduler: Box::new(scheduler),
             verifier: Box::new(verifier),
@@ -82,6 +126,7 @@ impl LightClient {
         Self {
             peer,
             options,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
             clock,
             scheduler,
             verifier,
@@ -89,8 +134,66 @@ impl LightClient {
         }
     }
 
+    /// Set the maximum number of consecutive bisection steps performed without
+    /// establishing trust in a new light block, after which verification fails
+    /// with `Error::BisectionDepthExceeded`.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BISECTION_DEPTH`].
+    pub fn with_max_bisection_depth(mut self, max_depth: usize) -> Self {
+        self.max_bisection_depth = max_depth;
+        self
+    }
+
+    /// Build the initial trusted state for subjective initialization, from a
+    /// header trusted by its hash at a known height, obtained out of band.
+    ///
+    /// The light block at the given height is fetched with `io`, and is only
+    /// trusted if the computed hash of its header matches the given hash and
+    /// its validator sets match the ones committed to in the header.
+    ///
+    /// Whether the trusted state is within the trusting period is left to the
+    /// caller, eg. when setting it as the trusted state of a
+    /// [`LightClientBuilder`](crate::builder::LightClientBuilder).
+    #[cfg(feature = "rust-crypto")]
+    pub fn init_from_trusted_hash(
+        height: Height,
+        hash: Hash,
+        io: &dyn Io,
+    ) -> Result<TrustedState, Error> {
+        use tendermint::crypto::default::Sha256;
+
+        let light_block = io
+            .fetch_light_block(AtHeight::At(height))
+            .map_err(Error::io)?;
+
+        if light_block.height() != height {
+            return Err(Error::trusted_height_mismatch(height, light_block.height()));
+        }
+
+        let header = &light_block.signed_header.header;
+        let header_hash = header.hash_with::<Sha256>();
+        if header_hash != hash {
+            return Err(Error::trusted_hash_mismatch(hash, header_hash));
+        }
+
+        ProdPredicates
+            .validator_sets_match(&light_block.validators, header.validators_hash)
+            .and_then(|()| {
+                ProdPredicates.next_validators_match(
+                    &light_block.next_validators,
+                    header.next_validators_hash,
+                )
+            })
+            .map_err(|e| Error::invalid_light_block(e.into_detail()))?;
+
+        Ok(light_block)
+    }
+
     /// Attempt to update the light client to the highest block of the primary node.
     ///
+    /// Verification starts from the highest trusted or verified block in the light
+    /// store, so that a client backed by a persistent store resumes where it left off.
+    ///
     /// Note: This function delegates the actual work to `verify_to_target`.
     pub fn verify_to_highest(&mut self, state: &mut State) -> Result<LightBlock, Error> {
         let target_block = self
@@ -130,6 +233,7 @@ impl LightClient {
     ///   [LCV-PRE-TP.1]
     /// - If the core verification loop invariant is violated [LCV-INV-TP.1]
     /// - If verification of a light block fails
+    /// - If bisection goes deeper than `max_bisection_depth` without establishing trust
     /// - If the fetching a light block from the primary node fails
     #[allow(clippy::nonminimal_bool)]
     #[ensures(
@@ -165,6 +269,51 @@ impl LightClient {
         }
     }
 
+    /// Verify a downloaded range of light blocks in sequence, starting from the
+    /// given trusted state.
+    ///
+    /// Each light block is verified against the latest trusted one, which is
+    /// advanced to every light block that verifies successfully. Unlike
+    /// [`LightClient::verify_to_target`], no light blocks are fetched, so a light
+    /// block which cannot be trusted is not bisected and fails.
+    ///
+    /// With [`BatchMode::AbortOnFirstFailure`], verification stops at the first
+    /// failure; with [`BatchMode::CollectAll`], the remaining light blocks are
+    /// still verified and a verdict is returned for each of them.
+    pub fn verify_batch(
+        &self,
+        trusted: &TrustedState,
+        blocks: &[LightBlock],
+        mode: BatchMode,
+    ) -> BatchResult {
+        let mut trusted = trusted;
+        let mut verdicts = Vec::with_capacity(blocks.len());
+
+        for block in blocks {
+            let verdict = self.verifier.verify_update_header(
+                block.as_untrusted_state(),
+                trusted.as_trusted_state(),
+                &self.options,
+                self.clock.now(),
+            );
+
+            let failed = verdict != Verdict::Success;
+            if !failed {
+                trusted = block;
+            }
+            verdicts.push(verdict);
+
+            if failed && mode == BatchMode::AbortOnFirstFailure {
+                break;
+            }
+        }
+
+        BatchResult {
+            verdicts,
+            trusted: trusted.clone(),
+        }
+    }
+
     /// Perform forward verification with bisection.
     fn verify_forward(
         &self,
@@ -172,6 +321,7 @@ impl LightClient {
         state: &mut State,
     ) -> Result<LightBlock, Error> {
         let mut current_height = target_height;
+        let mut depth = 0;
 
         loop {
             let now = self.clock.now();
@@ -227,6 +377,8 @@ impl LightClient {
 
                     // Log the trusted height as a dependency of the block at the current height
                     state.trace_block(current_height, trusted_block.height());
+
+                    depth = 0;
                 },
                 Verdict::Invalid(e) => {
                     // Verification failed, add the block to the light store with `Failed` status,
@@ -235,6 +387,16 @@ impl LightClient {
 
                     return Err(Error::invalid_light_block(e));
                 },
+                Verdict::Expired(e) => {
+                    // The trusted block has expired, so the current block cannot be verified
+                    // against it. Add the block to the light store with `Failed` status,
+                    // and abort.
+                    state.light_store.update(&current_block, Status::Failed);
+
+                    return Err(Error::invalid_light_block(
+                        VerificationErrorDetail::NotWithinTrustPeriod(e),
+                    ));
+                },
                 Verdict::NotEnoughTrust(_) => {
                     // The current block cannot be trusted because of a missing overlap in the
                     // validator sets. Add the block to the light store with
@@ -242,6 +404,14 @@ impl LightClient {
                     // attempt to raise the height of the highest trusted state
                     // until there is enough overlap.
                     state.light_store.update(&current_block, Status::Unverified);
+
+                    depth += 1;
+                    if depth > self.max_bisection_depth {
+                        return Err(Error::bisection_depth_exceeded(
+                            target_height,
+                            self.max_bisection_depth,
+                        ));
+                    }
                 },
             }
 
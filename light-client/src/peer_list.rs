@@ -0,0 +1,281 @@
+//! Provides a peer list keeping track of the primary, witnesses and faulty peers
+//! a light client is connected to.
+
+use std::collections::{BTreeSet, HashMap};
+
+use contracts::*;
+
+use crate::{errors::Error, verifier::types::PeerId};
+
+/// A generic container mapping `PeerId`s to some type `T`, which keeps track
+/// of the primary peer, the witnesses, and the peers which have been found to
+/// be faulty.
+///
+/// Faulty peers are quarantined: they are never promoted back to being a
+/// witness or the primary.
+#[derive(Clone, Debug)]
+pub struct PeerList<T> {
+    values: HashMap<PeerId, T>,
+    primary: PeerId,
+    witnesses: BTreeSet<PeerId>,
+    faulty_nodes: BTreeSet<PeerId>,
+}
+
+impl<T> PeerList<T> {
+    /// Returns a new builder for a peer list.
+    pub fn builder() -> PeerListBuilder<T> {
+        PeerListBuilder::default()
+    }
+
+    /// Invariant maintained by a `PeerList`
+    ///
+    /// ## Implements
+    /// - The primary is neither a witness nor faulty
+    /// - The witnesses and the faulty peers are disjoint
+    /// - Every peer in the list has an associated value
+    pub fn invariant(peer_list: &PeerList<T>) -> bool {
+        peer_list.witnesses.is_disjoint(&peer_list.faulty_nodes)
+            && !peer_list.witnesses.contains(&peer_list.primary)
+            && !peer_list.faulty_nodes.contains(&peer_list.primary)
+            && peer_list.values.contains_key(&peer_list.primary)
+            && peer_list
+                .witnesses
+                .iter()
+                .chain(peer_list.faulty_nodes.iter())
+                .all(|id| peer_list.values.contains_key(id))
+    }
+
+    /// Get a reference to the value associated with the given peer, if any.
+    pub fn get(&self, peer_id: &PeerId) -> Option<&T> {
+        self.values.get(peer_id)
+    }
+
+    /// Get the peer id of the current primary.
+    pub fn primary_id(&self) -> PeerId {
+        self.primary
+    }
+
+    /// Get a reference to the value associated with the current primary.
+    pub fn primary(&self) -> &T {
+        self.values
+            .get(&self.primary)
+            .expect("the primary is always part of the peer list")
+    }
+
+    /// Get the peer ids of the witnesses.
+    pub fn witnesses_ids(&self) -> &BTreeSet<PeerId> {
+        &self.witnesses
+    }
+
+    /// Get references to the values associated with the witnesses.
+    pub fn witnesses(&self) -> Vec<&T> {
+        self.witnesses
+            .iter()
+            .filter_map(|peer_id| self.values.get(peer_id))
+            .collect()
+    }
+
+    /// Get the peer ids of the peers which have been marked as faulty.
+    pub fn faulty_ids(&self) -> &BTreeSet<PeerId> {
+        &self.faulty_nodes
+    }
+
+    /// Whether the given peer has been marked as faulty.
+    pub fn is_faulty(&self, peer_id: &PeerId) -> bool {
+        self.faulty_nodes.contains(peer_id)
+    }
+
+    /// Mark the current primary as faulty and promote a witness in its place.
+    ///
+    /// Returns the peer id of the new primary, or `Error::NoWitnessesLeft`
+    /// if there are no witnesses left to promote, in which case the peer list
+    /// is left untouched.
+    #[ensures(Self::invariant(self))]
+    pub fn swap_primary_with_witness(&mut self) -> Result<PeerId, Error> {
+        let new_primary = self
+            .witnesses
+            .iter()
+            .next()
+            .copied()
+            .ok_or_else(Error::no_witnesses_left)?;
+
+        self.witnesses.remove(&new_primary);
+        self.faulty_nodes.insert(self.primary);
+        self.primary = new_primary;
+
+        Ok(new_primary)
+    }
+
+    /// Mark the given peer as faulty, moving it into quarantine.
+    ///
+    /// If the peer is the current primary, a witness is promoted in its place,
+    /// as per `swap_primary_with_witness`. Marking a peer which is unknown or
+    /// already faulty has no effect.
+    #[ensures(Self::invariant(self))]
+    pub fn mark_faulty(&mut self, peer_id: PeerId) -> Result<(), Error> {
+        if peer_id == self.primary {
+            self.swap_primary_with_witness()?;
+        } else if self.witnesses.remove(&peer_id) {
+            self.faulty_nodes.insert(peer_id);
+        }
+
+        Ok(())
+    }
+
+    /// Consume the peer list and return the values of all the peers it contains.
+    pub fn into_values(self) -> Vec<T> {
+        self.values.into_values().collect()
+    }
+}
+
+/// A builder of `PeerList` with a fluent API.
+#[must_use]
+pub struct PeerListBuilder<T> {
+    values: HashMap<PeerId, T>,
+    primary: Option<PeerId>,
+    witnesses: BTreeSet<PeerId>,
+}
+
+// This instance must be derived manually because the automatically
+// derived instance constrains T to be Default.
+// See https://github.com/rust-lang/rust/issues/26925
+impl<T> Default for PeerListBuilder<T> {
+    fn default() -> Self {
+        Self {
+            values: Default::default(),
+            primary: None,
+            witnesses: Default::default(),
+        }
+    }
+}
+
+impl<T> PeerListBuilder<T> {
+    /// Register the given peer id and value as the primary.
+    /// Overrides the previous primary if it was already set.
+    pub fn primary(mut self, peer_id: PeerId, value: T) -> Self {
+        self.primary = Some(peer_id);
+        self.values.insert(peer_id, value);
+        self
+    }
+
+    /// Register the given peer id and value as a witness.
+    #[requires(self.primary != Some(peer_id))]
+    pub fn witness(mut self, peer_id: PeerId, value: T) -> Self {
+        self.values.insert(peer_id, value);
+        self.witnesses.insert(peer_id);
+        self
+    }
+
+    /// Builds the peer list, or returns `Error::NoPrimary` if no primary was set.
+    #[ensures(ret.as_ref().map_or(true, PeerList::invariant))]
+    pub fn build(self) -> Result<PeerList<T>, Error> {
+        let primary = self.primary.ok_or_else(Error::no_primary)?;
+
+        let mut witnesses = self.witnesses;
+        witnesses.remove(&primary);
+
+        Ok(PeerList {
+            values: self.values,
+            primary,
+            witnesses,
+            faulty_nodes: BTreeSet::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::node::Id;
+
+    use super::*;
+    use crate::errors::ErrorDetail;
+
+    fn a() -> Id {
+        Id::new([1u8; 20])
+    }
+    fn b() -> Id {
+        Id::new([2u8; 20])
+    }
+    fn c() -> Id {
+        Id::new([3u8; 20])
+    }
+
+    fn dummy_peer_list() -> PeerList<u32> {
+        PeerList::builder()
+            .primary(a(), 1_u32)
+            .witness(b(), 2_u32)
+            .witness(c(), 3_u32)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn builder_requires_primary() {
+        let result = PeerList::builder().witness(b(), 2_u32).build();
+        assert!(matches!(
+            result.unwrap_err().detail(),
+            ErrorDetail::NoPrimary(_)
+        ));
+    }
+
+    #[test]
+    fn builder_succeeds() {
+        let peer_list = dummy_peer_list();
+        assert!(PeerList::invariant(&peer_list));
+        assert_eq!(peer_list.primary_id(), a());
+        assert_eq!(peer_list.primary(), &1);
+        assert_eq!(peer_list.witnesses(), vec![&2, &3]);
+        assert!(peer_list.faulty_ids().is_empty());
+    }
+
+    #[test]
+    fn swap_primary_promotes_witness() {
+        let mut peer_list = dummy_peer_list();
+
+        let new_primary = peer_list.swap_primary_with_witness().unwrap();
+        assert_eq!(new_primary, b());
+        assert_eq!(peer_list.primary_id(), b());
+        assert_eq!(peer_list.primary(), &2);
+        assert!(peer_list.is_faulty(&a()));
+        assert_eq!(
+            peer_list.witnesses_ids().iter().collect::<Vec<_>>(),
+            vec![&c()]
+        );
+    }
+
+    #[test]
+    fn mark_faulty_witness_is_never_promoted() {
+        let mut peer_list = dummy_peer_list();
+
+        peer_list.mark_faulty(b()).unwrap();
+        assert!(peer_list.is_faulty(&b()));
+        assert_eq!(peer_list.primary_id(), a());
+
+        peer_list.mark_faulty(a()).unwrap();
+        assert_eq!(peer_list.primary_id(), c());
+        assert!(peer_list.is_faulty(&a()));
+        assert!(peer_list.witnesses_ids().is_empty());
+    }
+
+    #[test]
+    fn exhausting_all_witnesses() {
+        let mut peer_list = dummy_peer_list();
+
+        assert_eq!(peer_list.swap_primary_with_witness().unwrap(), b());
+        assert_eq!(peer_list.swap_primary_with_witness().unwrap(), c());
+
+        let result = peer_list.swap_primary_with_witness();
+        assert!(matches!(
+            result.unwrap_err().detail(),
+            ErrorDetail::NoWitnessesLeft(_)
+        ));
+
+        // The primary is left untouched when no witness can be promoted
+        assert_eq!(peer_list.primary_id(), c());
+        assert!(peer_list.is_faulty(&a()));
+        assert!(peer_list.is_faulty(&b()));
+        assert!(!peer_list.is_faulty(&c()));
+
+        assert!(peer_list.mark_faulty(c()).is_err());
+    }
+}
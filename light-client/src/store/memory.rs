@@ -101,3 +101,104 @@ impl LightStore for MemoryStore {
         Box::new(light_blocks.into_iter())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{light_block::TmLightBlock as TGLightBlock, Generator, LightChain};
+
+    use super::*;
+
+    #[test]
+    fn update_transitions_status() {
+        with_blocks(1, |mut store, blocks| {
+            let block = blocks[0].clone();
+            let height = block.height();
+
+            store.insert(block.clone(), Status::Unverified);
+            assert_eq!(store.get(height, Status::Unverified), Some(block.clone()));
+
+            store.update(&block, Status::Verified);
+            assert_eq!(store.get(height, Status::Unverified), None);
+            assert_eq!(store.get(height, Status::Verified), Some(block.clone()));
+
+            store.update(&block, Status::Trusted);
+            assert_eq!(store.get(height, Status::Verified), None);
+            assert_eq!(store.get(height, Status::Trusted), Some(block.clone()));
+            assert_eq!(store.get_non_failed(height), Some((block, Status::Trusted)));
+        })
+    }
+
+    #[test]
+    fn height_has_at_most_one_status() {
+        with_blocks(1, |mut store, blocks| {
+            let block = blocks[0].clone();
+            let height = block.height();
+
+            store.insert(block.clone(), Status::Verified);
+            store.insert(block.clone(), Status::Failed);
+
+            assert_eq!(store.get(height, Status::Verified), None);
+            assert_eq!(store.get(height, Status::Failed), Some(block));
+            assert_eq!(store.get_non_failed(height), None);
+        })
+    }
+
+    #[test]
+    fn remove_only_matching_status() {
+        with_blocks(1, |mut store, blocks| {
+            let block = blocks[0].clone();
+            let height = block.height();
+
+            store.insert(block.clone(), Status::Verified);
+            store.remove(height, Status::Trusted);
+            assert_eq!(store.get(height, Status::Verified), Some(block));
+
+            store.remove(height, Status::Verified);
+            assert_eq!(store.get(height, Status::Verified), None);
+        })
+    }
+
+    #[test]
+    fn highest_by_status() {
+        with_blocks(5, |mut store, blocks| {
+            store.insert(blocks[0].clone(), Status::Trusted);
+            store.insert(blocks[1].clone(), Status::Verified);
+            store.insert(blocks[2].clone(), Status::Trusted);
+            store.insert(blocks[3].clone(), Status::Verified);
+            store.insert(blocks[4].clone(), Status::Unverified);
+
+            assert_eq!(store.highest(Status::Trusted), Some(blocks[2].clone()));
+            assert_eq!(store.highest(Status::Verified), Some(blocks[3].clone()));
+            assert_eq!(store.highest(Status::Unverified), Some(blocks[4].clone()));
+            assert_eq!(store.highest(Status::Failed), None);
+            assert_eq!(store.lowest(Status::Verified), Some(blocks[1].clone()));
+            assert_eq!(store.highest_trusted_or_verified(), Some(blocks[3].clone()));
+            assert_eq!(
+                store.highest_before(blocks[3].height(), Status::Trusted),
+                Some(blocks[2].clone())
+            );
+            assert_eq!(store.all(Status::Trusted).count(), 2);
+        })
+    }
+
+    fn with_blocks(height: u64, f: impl FnOnce(MemoryStore, Vec<LightBlock>)) {
+        let chain = LightChain::default_with_length(height);
+        let blocks = chain
+            .light_blocks
+            .into_iter()
+            .map(|lb| lb.generate().unwrap())
+            .map(testgen_to_lb)
+            .collect::<Vec<_>>();
+
+        f(MemoryStore::new(), blocks)
+    }
+
+    fn testgen_to_lb(tm_lb: TGLightBlock) -> LightBlock {
+        LightBlock {
+            signed_header: tm_lb.signed_header,
+            validators: tm_lb.validators,
+            next_validators: tm_lb.next_validators,
+            provider: tm_lb.provider,
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
-use tendermint::{block::Height as HeightStr, evidence::Duration as DurationStr};
+use tendermint::{block::Height as HeightStr, Duration as DurationStr};
 use tendermint_rpc as rpc;
 
 use crate::{
@@ -21,7 +21,7 @@ use crate::{
 };
 
 #[cfg(feature = "rust-crypto")]
-use crate::verifier::{Verdict, Verifier};
+use crate::verifier::{operations::VotingPowerTally, Verdict, Verifier};
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct TestCases<LB> {
@@ -134,6 +134,29 @@ pub fn verify_single(
     clock_drift: Duration,
     now: Time,
 ) -> Result<LightBlock, Verdict> {
+    verify_single_ref(
+        &trusted_block,
+        &input,
+        trust_threshold,
+        trusting_period,
+        clock_drift,
+        now,
+    )?;
+    Ok(input)
+}
+
+/// Same as [`verify_single`], but borrows the trusted and input blocks, so
+/// that verifying a sequence of blocks does not require cloning them at every
+/// step. Returns the input block once verified.
+#[cfg(feature = "rust-crypto")]
+pub fn verify_single_ref<'a>(
+    trusted_block: &LightBlock,
+    input: &'a LightBlock,
+    trust_threshold: TrustThreshold,
+    trusting_period: Duration,
+    clock_drift: Duration,
+    now: Time,
+) -> Result<&'a LightBlock, Verdict> {
     use crate::verifier::options::Options;
 
     let verifier = crate::verifier::ProdVerifier::default();
@@ -157,6 +180,222 @@ pub fn verify_single(
     }
 }
 
+/// Outcome of a successful [`verify_single_verbose`].
+#[cfg(feature = "rust-crypto")]
+#[derive(Clone, Debug)]
+pub struct VerificationReport {
+    /// The verified block
+    pub verified: LightBlock,
+    /// Voting power of the trusted next validators which signed the commit of
+    /// the verified block, out of their total voting power
+    pub tally: VotingPowerTally,
+    /// Voting power of the verified block's own validators which signed its
+    /// commit, out of their total voting power
+    pub signers_tally: VotingPowerTally,
+    /// Whether the verified block is adjacent to the trusted block, as opposed
+    /// to skipping over intermediate blocks
+    pub adjacent: bool,
+}
+
+#[cfg(feature = "rust-crypto")]
+impl VerificationReport {
+    /// The fraction of the trusted next validators' voting power which signed
+    /// the commit of the verified block, or 0 if they have no voting power.
+    pub fn trust_ratio(&self) -> f64 {
+        if self.tally.total == 0 {
+            0.0
+        } else {
+            self.tally.tallied as f64 / self.tally.total as f64
+        }
+    }
+}
+
+/// Same as [`verify_single`], but reports the voting power of the trusted
+/// next validators which signed the commit of the verified block, which may
+/// be well above the trust threshold, along with that of its own validators.
+#[cfg(feature = "rust-crypto")]
+pub fn verify_single_verbose(
+    trusted_block: LightBlock,
+    input: LightBlock,
+    trust_threshold: TrustThreshold,
+    trusting_period: Duration,
+    clock_drift: Duration,
+    now: Time,
+) -> Result<VerificationReport, Verdict> {
+    let adjacent = input.height() == trusted_block.height().increment();
+    let next_validators = trusted_block.next_validators.clone();
+
+    let verified = verify_single(
+        trusted_block,
+        input,
+        trust_threshold,
+        trusting_period,
+        clock_drift,
+        now,
+    )?;
+
+    let tallies = tally_voting_power(&next_validators, &verified, trust_threshold)?;
+
+    Ok(VerificationReport {
+        verified,
+        tally: tallies.trusted,
+        signers_tally: tallies.untrusted,
+        adjacent,
+    })
+}
+
+/// The voting power which signed the commit of a light block, tallied both
+/// against the trusted next validators and against the block's own validators.
+///
+/// Skipping verification needs both: the former to bridge trust to the block,
+/// the latter for its commit to be valid.
+#[cfg(feature = "rust-crypto")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VotingPowerTallies {
+    /// Voting power of the trusted next validators which signed the commit,
+    /// against the trust threshold
+    pub trusted: VotingPowerTally,
+    /// Voting power of the block's own validators which signed the commit,
+    /// against a threshold of 2/3
+    pub untrusted: VotingPowerTally,
+}
+
+/// Tallies all the voting power which signed the commit of `input`, both
+/// against `trusted_next_validators` and against the validators of `input`,
+/// regardless of whether `input` verifies.
+#[cfg(feature = "rust-crypto")]
+pub fn tally_voting_power(
+    trusted_next_validators: &ValidatorSet,
+    input: &LightBlock,
+    trust_threshold: TrustThreshold,
+) -> Result<VotingPowerTallies, Verdict> {
+    use crate::verifier::operations::{ProdVotingPowerCalculator, VotingPowerCalculator};
+
+    // The calculator stops tallying once the threshold is reached, and a
+    // threshold of 1/1 is never reached, so that every signature is tallied.
+    let all = TrustThreshold::new(1, 1).unwrap();
+    let (mut trusted, mut untrusted) = ProdVotingPowerCalculator::default()
+        .voting_power_in_sets(
+            &input.signed_header,
+            (trusted_next_validators, all),
+            (&input.validators, all),
+        )
+        .map_err(|e| Verdict::from(Err(e)))?;
+    trusted.trust_threshold = trust_threshold;
+    untrusted.trust_threshold = TrustThreshold::TWO_THIRDS;
+
+    Ok(VotingPowerTallies { trusted, untrusted })
+}
+
+/// Runs every check performed by [`verify_single`] independently, and
+/// returns the verdicts of all the failing ones instead of only the first.
+///
+/// This is meant for diagnosing why a block does not verify, and is much
+/// slower than [`verify_single`]. An empty result means that the block
+/// verifies.
+#[cfg(feature = "rust-crypto")]
+pub fn verify_single_diagnose(
+    trusted_block: &LightBlock,
+    input: &LightBlock,
+    trust_threshold: TrustThreshold,
+    trusting_period: Duration,
+    clock_drift: Duration,
+    now: Time,
+) -> Vec<Verdict> {
+    use crate::verifier::{
+        operations::{ProdCommitValidator, ProdVotingPowerCalculator},
+        predicates::{ProdPredicates, VerificationPredicates},
+    };
+
+    let predicates = ProdPredicates;
+    let calculator = ProdVotingPowerCalculator::default();
+    let untrusted = input.as_untrusted_state();
+    let trusted = trusted_block.as_trusted_state();
+    let header = &untrusted.signed_header.header;
+    let adjacent = untrusted.height() == trusted.height.increment();
+
+    let results = [
+        predicates.validator_sets_match(untrusted.validators, header.validators_hash),
+        predicates.next_validators_match(&input.next_validators, header.next_validators_hash),
+        predicates.header_matches_commit(header, untrusted.signed_header.commit.block_id.hash),
+        predicates.valid_commit(
+            untrusted.signed_header,
+            untrusted.validators,
+            &ProdCommitValidator,
+        ),
+        predicates.is_within_trust_period(trusted.header_time, trusting_period, now),
+        predicates.is_monotonic_bft_time(header.time, trusted.header_time),
+        predicates.is_matching_chain_id(&header.chain_id, trusted.chain_id),
+        if adjacent {
+            predicates
+                .valid_next_validator_set(header.validators_hash, trusted.next_validators_hash)
+        } else {
+            predicates.is_monotonic_height(header.height, trusted.height)
+        },
+        predicates.is_header_from_past(header.time, clock_drift, now),
+        if adjacent {
+            predicates.has_sufficient_signers_overlap(
+                untrusted.signed_header,
+                untrusted.validators,
+                &calculator,
+            )
+        } else {
+            predicates.has_sufficient_validators_and_signers_overlap(
+                untrusted.signed_header,
+                trusted.next_validators,
+                &trust_threshold,
+                untrusted.validators,
+                &calculator,
+            )
+        },
+    ];
+
+    results
+        .into_iter()
+        .filter(Result::is_err)
+        .map(Verdict::from)
+        .collect()
+}
+
+/// Verifies the `input` block against the closest trusted ancestor among
+/// `trusted_blocks`, i.e. the highest one below the height of `input`.
+///
+/// Returns the verified block together with the trusted block it was verified
+/// against. A `Verdict::NotEnoughTrust` error means that trust cannot be
+/// established directly from that ancestor, and that bisecting between the
+/// two is required, while a `NoTrustedAncestor` error means that none of the
+/// trusted blocks is below `input`.
+#[cfg(feature = "rust-crypto")]
+pub fn verify_against_trusted(
+    trusted_blocks: &[LightBlock],
+    input: LightBlock,
+    trust_threshold: TrustThreshold,
+    trusting_period: Duration,
+    clock_drift: Duration,
+    now: Time,
+) -> Result<(LightBlock, &LightBlock), Verdict> {
+    use crate::verifier::errors::VerificationError;
+
+    let trusted_block = trusted_blocks
+        .iter()
+        .filter(|lb| lb.height() < input.height())
+        .max_by_key(|lb| lb.height())
+        .ok_or_else(|| {
+            Verdict::from(Err(VerificationError::no_trusted_ancestor(input.height())))
+        })?;
+
+    let verified = verify_single(
+        trusted_block.clone(),
+        input,
+        trust_threshold,
+        trusting_period,
+        clock_drift,
+        now,
+    )?;
+
+    Ok((verified, trusted_block))
+}
+
 pub fn verify_bisection(
     untrusted_height: Height,
     light_client: &mut LightClient,
@@ -166,3 +405,250 @@ pub fn verify_bisection(
         .verify_to_target(untrusted_height, state)
         .map(|_| state.get_trace(untrusted_height))
 }
+
+#[cfg(all(test, feature = "rust-crypto"))]
+mod test {
+    use tendermint_testgen::{
+        light_block::TmLightBlock as TGLightBlock, Generator, Header,
+        LightBlock as TGLightBlockGen, LightChain, Validator,
+    };
+
+    use super::*;
+    use crate::verifier::errors::VerificationErrorDetail;
+
+    fn blocks(length: u64) -> Vec<LightBlock> {
+        LightChain::default_with_length(length)
+            .light_blocks
+            .into_iter()
+            .map(|lb| testgen_to_lb(lb.generate().unwrap()))
+            .collect()
+    }
+
+    fn testgen_to_lb(tm_lb: TGLightBlock) -> LightBlock {
+        LightBlock {
+            signed_header: tm_lb.signed_header,
+            validators: tm_lb.validators,
+            next_validators: tm_lb.next_validators,
+            provider: tm_lb.provider,
+        }
+    }
+
+    /// A light block at the given height, signed by and handing over to the
+    /// given validators.
+    fn light_block(validators: &[Validator], height: u64) -> LightBlock {
+        let header = Header::new(validators)
+            .height(height)
+            .chain_id("chain")
+            .next_validators(validators)
+            .time(Time::from_unix_timestamp(height as i64, 0).unwrap());
+        testgen_to_lb(
+            TGLightBlockGen::new_default_with_header(header)
+                .generate()
+                .unwrap(),
+        )
+    }
+
+    fn verify(
+        trusted_blocks: &[LightBlock],
+        input: &LightBlock,
+    ) -> Result<(LightBlock, Height), Verdict> {
+        let now = (input.time() + Duration::from_secs(1)).unwrap();
+        verify_against_trusted(
+            trusted_blocks,
+            input.clone(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            now,
+        )
+        .map(|(verified, trusted)| (verified, trusted.height()))
+    }
+
+    #[test]
+    fn verifies_against_closest_trusted_ancestor() {
+        let blocks = blocks(20);
+        let trusted = [
+            blocks[0].clone(),
+            blocks[2].clone(),
+            blocks[19].clone(),
+            blocks[6].clone(),
+        ];
+
+        let (verified, used) = verify(&trusted, &blocks[4]).unwrap();
+        assert_eq!(verified, blocks[4]);
+        assert_eq!(used, blocks[2].height());
+
+        let (verified, used) = verify(&trusted, &blocks[10]).unwrap();
+        assert_eq!(verified, blocks[10]);
+        assert_eq!(used, blocks[6].height());
+    }
+
+    #[test]
+    fn fails_without_trusted_ancestor() {
+        let blocks = blocks(10);
+        let trusted = [blocks[5].clone(), blocks[9].clone()];
+
+        let result = verify(&trusted, &blocks[3]);
+        assert!(matches!(
+            result,
+            Err(Verdict::Invalid(VerificationErrorDetail::NoTrustedAncestor(e)))
+                if e.target == blocks[3].height()
+        ));
+    }
+
+    #[test]
+    fn verify_single_verbose_reports_trust_ratio() {
+        let all = [
+            Validator::new("a").voting_power(10),
+            Validator::new("b").voting_power(20),
+            Validator::new("c").voting_power(30),
+            Validator::new("d").voting_power(40),
+        ];
+        let trusted = light_block(&all, 1);
+        // Only `b` and `d` remain, together holding 60 out of the 100 voting
+        // power of the trusted next validators.
+        let untrusted = light_block(&[all[1].clone(), all[3].clone()], 5);
+
+        let report = verify_single_verbose(
+            trusted,
+            untrusted.clone(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            Time::from_unix_timestamp(10, 0).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(report.verified, untrusted);
+        assert!(!report.adjacent);
+        assert_eq!(report.tally.tallied, 60);
+        assert_eq!(report.tally.total, 100);
+        assert_eq!(report.tally.trust_threshold, TrustThreshold::ONE_THIRD);
+        assert_eq!(report.trust_ratio(), 0.6);
+        assert_eq!(report.signers_tally.tallied, 60);
+        assert_eq!(report.signers_tally.total, 60);
+    }
+
+    #[test]
+    fn trust_ratio_without_voting_power_is_zero() {
+        let empty = VotingPowerTally {
+            total: 0,
+            tallied: 0,
+            trust_threshold: TrustThreshold::ONE_THIRD,
+        };
+        let report = VerificationReport {
+            verified: blocks(1).remove(0),
+            tally: empty,
+            signers_tally: empty,
+            adjacent: true,
+        };
+        assert_eq!(report.trust_ratio(), 0.0);
+    }
+
+    #[test]
+    fn tally_voting_power_distinguishes_trusted_and_untrusted_sets() {
+        let all = [
+            Validator::new("a").voting_power(10),
+            Validator::new("b").voting_power(20),
+            Validator::new("c").voting_power(30),
+            Validator::new("d").voting_power(40),
+            Validator::new("e").voting_power(50),
+        ];
+        let trusted = light_block(&all[..4], 1);
+        // Only `a` remains among the trusted next validators, holding 10 out
+        // of their 100 voting power, while the commit is fully signed by the
+        // block's own validators.
+        let untrusted = light_block(&[all[0].clone(), all[4].clone()], 5);
+
+        let verdict = verify_single(
+            trusted.clone(),
+            untrusted.clone(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            Time::from_unix_timestamp(10, 0).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(verdict, Verdict::NotEnoughTrust(_)), "{verdict:?}");
+
+        let tallies = tally_voting_power(
+            &trusted.next_validators,
+            &untrusted,
+            TrustThreshold::ONE_THIRD,
+        )
+        .unwrap();
+
+        assert_eq!(tallies.trusted.tallied, 10);
+        assert_eq!(tallies.trusted.total, 100);
+        assert_eq!(tallies.trusted.trust_threshold, TrustThreshold::ONE_THIRD);
+        assert_eq!(tallies.untrusted.tallied, 60);
+        assert_eq!(tallies.untrusted.total, 60);
+        assert_eq!(
+            tallies.untrusted.trust_threshold,
+            TrustThreshold::TWO_THIRDS
+        );
+    }
+
+    #[test]
+    fn verify_single_verbose_reports_adjacent() {
+        let blocks = blocks(2);
+        let now = (blocks[1].time() + Duration::from_secs(1)).unwrap();
+
+        let report = verify_single_verbose(
+            blocks[0].clone(),
+            blocks[1].clone(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            now,
+        )
+        .unwrap();
+
+        assert!(report.adjacent);
+        assert_eq!(report.tally.tallied, report.tally.total);
+        assert_eq!(report.trust_ratio(), 1.0);
+    }
+
+    #[test]
+    fn verify_single_diagnose_reports_every_failure() {
+        let validators = |names: [&str; 2]| names.map(|name| Validator::new(name).voting_power(50));
+        let trusted = light_block(&validators(["a", "b"]), 1);
+        let mut untrusted = light_block(&validators(["c", "d"]), 5);
+        // The header no longer matches the block ID signed by the commit.
+        untrusted.signed_header.header.app_hash = vec![0xff; 32].try_into().unwrap();
+        // Long after the end of the trusting period.
+        let now = Time::from_unix_timestamp(10_000, 0).unwrap();
+
+        let verdicts = verify_single_diagnose(
+            &trusted,
+            &untrusted,
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            now,
+        );
+
+        assert_eq!(verdicts.len(), 3, "{verdicts:?}");
+        assert!(matches!(
+            verdicts[0],
+            Verdict::Invalid(VerificationErrorDetail::InvalidCommitValue(_))
+        ));
+        assert!(matches!(verdicts[1], Verdict::Expired(_)));
+        assert!(matches!(
+            &verdicts[2],
+            Verdict::NotEnoughTrust(tally) if tally.tallied == 0
+        ));
+
+        let blocks = blocks(2);
+        let now = (blocks[1].time() + Duration::from_secs(1)).unwrap();
+        let verdicts = verify_single_diagnose(
+            &blocks[0],
+            &blocks[1],
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(3600),
+            Duration::from_secs(1),
+            now,
+        );
+        assert_eq!(verdicts, vec![]);
+    }
+}
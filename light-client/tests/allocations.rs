@@ -0,0 +1,101 @@
+//! Checks that verifying a sequence of light blocks with `verify_single_ref`,
+//! which borrows them, avoids the clones `verify_single` requires at every step.
+
+#![cfg(feature = "rust-crypto")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    time::Duration,
+};
+
+use tendermint_light_client::{
+    tests::{verify_single, verify_single_ref},
+    verifier::types::{LightBlock, Time},
+};
+use tendermint_testgen::{Generator, LightChain};
+
+const STEPS: u64 = 10;
+
+/// Counts the allocations made by each thread.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Returns the number of allocations made by the current thread while running `f`.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn verify_single_ref_does_not_clone_blocks() {
+    let blocks: Vec<LightBlock> = LightChain::default_with_length(STEPS + 1)
+        .light_blocks
+        .into_iter()
+        .map(|lb| {
+            let lb = lb.generate().unwrap();
+            LightBlock::new(
+                lb.signed_header,
+                lb.validators,
+                lb.next_validators,
+                lb.provider,
+            )
+        })
+        .collect();
+
+    let trusting_period = Duration::from_secs(3600);
+    let now = Time::from_unix_timestamp(STEPS as i64 + 10, 0).unwrap();
+
+    let owned = count_allocations(|| {
+        let mut trusted = blocks[0].clone();
+        for block in &blocks[1..] {
+            trusted = verify_single(
+                trusted.clone(),
+                block.clone(),
+                Default::default(),
+                trusting_period,
+                Default::default(),
+                now,
+            )
+            .unwrap();
+        }
+    });
+    let borrowed = count_allocations(|| {
+        let mut trusted = &blocks[0];
+        for block in &blocks[1..] {
+            trusted = verify_single_ref(
+                trusted,
+                block,
+                Default::default(),
+                trusting_period,
+                Default::default(),
+                now,
+            )
+            .unwrap();
+        }
+    });
+    let per_clone = count_allocations(|| drop(blocks[0].clone()));
+
+    // The owned variant clones the initial trusted block once, then both
+    // blocks at every step.
+    assert!(per_clone > 0);
+    assert_eq!(owned - borrowed, (2 * STEPS as usize + 1) * per_clone);
+}
@@ -0,0 +1,81 @@
+//! Tests for the `AsyncIo` component over a mock async RPC client.
+
+#![cfg(feature = "rpc-client")]
+
+use tendermint_light_client::components::io::{AsyncIo, AsyncProdIo, AtHeight};
+use tendermint_rpc::{
+    endpoint::{commit, validators},
+    Method, MockClient, MockRequestMethodMatcher,
+};
+use tendermint_testgen::{light_block::TmLightBlock, Generator, LightBlock as TestgenLightBlock};
+
+/// Wraps the given result in a JSON-RPC response.
+fn json_rpc_response(result: impl serde::Serialize) -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "",
+        "result": result,
+    })
+    .to_string()
+}
+
+/// A mock async client serving the `/commit` and `/validators` endpoints
+/// for the given light block.
+fn mock_client(block: &TmLightBlock) -> MockClient<MockRequestMethodMatcher> {
+    let commit = commit::Response {
+        signed_header: block.signed_header.clone(),
+        canonical: true,
+    };
+    let vals = block.validators.validators().clone();
+    let total = vals.len() as i32;
+    let validators = validators::Response::new(block.signed_header.header.height, vals, total);
+
+    let matcher = MockRequestMethodMatcher::default()
+        .map(Method::Commit, Ok(json_rpc_response(commit)))
+        .map(Method::Validators, Ok(json_rpc_response(validators)));
+
+    let (client, _driver) = MockClient::new(matcher);
+    client
+}
+
+fn block_on<F: std::future::Future>(f: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(f)
+}
+
+#[test]
+fn fetch_light_block_assembles_commit_and_validators() {
+    let expected = TestgenLightBlock::new_default(3).generate().unwrap();
+    let io = AsyncProdIo::new(expected.provider, mock_client(&expected));
+
+    for height in [AtHeight::At(3u32.into()), AtHeight::Highest] {
+        let light_block = block_on(io.fetch_light_block(height)).unwrap();
+
+        assert_eq!(light_block.signed_header, expected.signed_header);
+        assert_eq!(light_block.validators.hash(), expected.validators.hash());
+        assert_eq!(
+            light_block.next_validators.hash(),
+            expected.next_validators.hash()
+        );
+        assert_eq!(
+            light_block
+                .validators
+                .proposer()
+                .as_ref()
+                .map(|p| p.address),
+            Some(expected.signed_header.header.proposer_address)
+        );
+        assert_eq!(light_block.provider, expected.provider);
+    }
+}
+
+#[test]
+fn async_io_is_object_safe() {
+    let block = TestgenLightBlock::new_default(1).generate().unwrap();
+    let io: Box<dyn AsyncIo> = Box::new(AsyncProdIo::new(block.provider, mock_client(&block)));
+
+    let light_block = block_on(io.fetch_light_block(AtHeight::Highest)).unwrap();
+    assert_eq!(light_block.height(), block.signed_header.header.height);
+}
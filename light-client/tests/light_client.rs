@@ -1,22 +1,32 @@
 use std::{collections::HashMap, time::Duration};
 
+use tendermint::Time;
 use tendermint_light_client::{
+    builder::LightClientBuilder,
     components::{
+        clock::FixedClock,
         io::{AtHeight, Io},
         scheduler,
     },
-    errors::Error,
-    light_client::LightClient,
+    errors::{Error, ErrorDetail},
+    instance::Instance,
+    light_client::{BatchMode, LightClient},
     state::State,
     store::{memory::MemoryStore, LightStore},
     tests::*,
     verifier::{
+        errors::VerificationErrorDetail,
+        operations::VotingPowerTally,
         options::Options,
-        types::{LightBlock, Status},
-        ProdVerifier,
+        predicates::ProdPredicates,
+        types::{LightBlock, Status, TrustedBlockState, UntrustedBlockState},
+        ProdVerifier, Verdict, Verifier,
     },
 };
-use tendermint_testgen::{light_block::default_peer_id, Tester};
+use tendermint_testgen::{
+    helpers::get_time, light_block::default_peer_id, Generator, LightBlock as TestgenLightBlock,
+    LightChain, Tester,
+};
 
 // Link to JSON test files repo:
 // https://github.com/informalsystems/conformance-tests
@@ -97,7 +107,7 @@ fn forward_test(tc: LightClientTest<LightBlock>) {
     match test_result.new_states {
         Ok(new_states) => {
             assert_eq!(new_states[0].height(), expected_state.height());
-            assert_eq!(new_states[0], expected_state);
+            assert!(new_states[0].same_block(&expected_state));
             assert!(!expect_error);
         },
         Err(e) => {
@@ -116,3 +126,460 @@ fn run_tests() {
     tester.run_foreach_in_dir("bisection/single_peer");
     tester.finalize();
 }
+
+/// The light blocks of the default testgen chain of the given length.
+fn chain_blocks(length: u64) -> Vec<LightBlock> {
+    LightChain::default_with_length(length)
+        .light_blocks
+        .into_iter()
+        .map(|lb| lb.generate().unwrap())
+        .map(|lb| LightBlock {
+            signed_header: lb.signed_header,
+            validators: lb.validators,
+            next_validators: lb.next_validators,
+            provider: lb.provider,
+        })
+        .collect()
+}
+
+fn make(length: u64, now: Time, verifier: impl Verifier + 'static) -> (LightClient, State) {
+    let io = MockIo::new(chain_blocks(length));
+    let trusted_state = io.fetch_light_block(AtHeight::At(1_u32.into())).unwrap();
+
+    let mut light_store = MemoryStore::new();
+    light_store.insert(trusted_state, Status::Trusted);
+
+    let state = State {
+        light_store: Box::new(light_store),
+        verification_trace: HashMap::new(),
+    };
+
+    let options = Options {
+        trust_threshold: Default::default(),
+        trusting_period: Duration::from_secs(60),
+        clock_drift: Duration::from_secs(10),
+    };
+
+    let light_client = LightClient::new(
+        default_peer_id(),
+        options,
+        FixedClock::new(now),
+        scheduler::basic_bisecting_schedule,
+        verifier,
+        io,
+    );
+
+    (light_client, state)
+}
+
+#[test]
+fn fixed_clock_within_trusting_period() {
+    let now = get_time(30).unwrap();
+    let (light_client, mut state) = make(3, now, ProdVerifier::default());
+    let verified = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap();
+    assert_eq!(verified.height(), 3_u32.into());
+}
+
+#[test]
+fn fixed_clock_past_trusting_period() {
+    // The trusted block at height 1 expires one trusting period after its time
+    let now = get_time(1 + 60 + 1).unwrap();
+    let (light_client, mut state) = make(3, now, ProdVerifier::default());
+    let err = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap_err();
+    assert!(matches!(
+        err.detail(),
+        ErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+    ));
+}
+
+/// A verifier which never lets trust be established in a light block.
+struct NeverTrusted;
+
+impl Verifier for NeverTrusted {
+    fn verify_update_header(
+        &self,
+        _untrusted: UntrustedBlockState<'_>,
+        _trusted: TrustedBlockState<'_>,
+        options: &Options,
+        _now: Time,
+    ) -> Verdict {
+        Verdict::NotEnoughTrust(VotingPowerTally {
+            total: 100,
+            tallied: 0,
+            trust_threshold: options.trust_threshold,
+        })
+    }
+
+    fn verify_misbehaviour_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        self.verify_update_header(untrusted, trusted, options, now)
+    }
+}
+
+#[test]
+fn bisection_depth_exceeded() {
+    // Bisecting from height 1 to height 64 takes more than 3 steps to reach adjacency
+    let (light_client, mut state) = make(64, get_time(30).unwrap(), NeverTrusted);
+    let light_client = light_client.with_max_bisection_depth(3);
+
+    let err = light_client
+        .verify_to_target(64_u32.into(), &mut state)
+        .unwrap_err();
+    match err.detail() {
+        ErrorDetail::BisectionDepthExceeded(e) => {
+            assert_eq!(e.target_height, 64_u32.into());
+            assert_eq!(e.max_depth, 3);
+        },
+        e => panic!("unexpected error: {e:?}"),
+    }
+}
+
+/// A verifier which only establishes trust in light blocks adjacent to a trusted one,
+/// forcing the light client to bisect all the way down.
+struct AdjacentOnly(ProdVerifier);
+
+impl Verifier for AdjacentOnly {
+    fn verify_update_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        if untrusted.height().value() > trusted.height.value() + 1 {
+            return NeverTrusted.verify_update_header(untrusted, trusted, options, now);
+        }
+        self.0
+            .verify_update_header(untrusted, trusted, options, now)
+    }
+
+    fn verify_misbehaviour_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        self.verify_update_header(untrusted, trusted, options, now)
+    }
+}
+
+#[test]
+fn verify_to_highest_syncs_in_multiple_steps() {
+    let (mut light_client, mut state) = make(
+        8,
+        get_time(30).unwrap(),
+        AdjacentOnly(ProdVerifier::default()),
+    );
+
+    let verified = light_client.verify_to_highest(&mut state).unwrap();
+    assert_eq!(verified.height(), 8_u32.into());
+
+    // Every intermediate block had to be verified and persisted to the store
+    for height in 2..=8_u32 {
+        assert!(
+            state
+                .light_store
+                .get_trusted_or_verified(height.into())
+                .is_some(),
+            "missing verified block at height {height}"
+        );
+    }
+    assert_eq!(
+        state.light_store.highest_trusted_or_verified(),
+        Some(verified)
+    );
+}
+
+#[test]
+fn verify_to_highest_past_trusting_period() {
+    let now = get_time(1 + 60 + 1).unwrap();
+    let (mut light_client, mut state) = make(8, now, ProdVerifier::default());
+
+    let err = light_client.verify_to_highest(&mut state).unwrap_err();
+    assert!(matches!(
+        err.detail(),
+        ErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+    ));
+    assert_eq!(
+        state
+            .light_store
+            .highest_trusted_or_verified()
+            .map(|lb| lb.height()),
+        Some(1_u32.into())
+    );
+}
+
+#[test]
+fn verify_to_highest_resumes_from_store() {
+    let (mut light_client, mut state) = make(8, get_time(30).unwrap(), ProdVerifier::default());
+
+    // A previous run already verified the chain up to height 5
+    let verified = chain_blocks(8).swap_remove(4);
+    state.light_store.insert(verified, Status::Verified);
+
+    let highest = light_client.verify_to_highest(&mut state).unwrap();
+    assert_eq!(highest.height(), 8_u32.into());
+
+    let trace: Vec<_> = state
+        .get_trace(8_u32.into())
+        .iter()
+        .map(|lb| lb.height().value())
+        .collect();
+    assert_eq!(trace, vec![5, 8]);
+}
+
+#[test]
+fn verify_to_target_stops_at_mid_range_target() {
+    let (light_client, mut state) = make(
+        16,
+        get_time(30).unwrap(),
+        AdjacentOnly(ProdVerifier::default()),
+    );
+
+    let verified = light_client
+        .verify_to_target(10_u32.into(), &mut state)
+        .unwrap();
+    assert_eq!(verified, chain_blocks(16).swap_remove(9));
+
+    // The intermediate blocks were persisted, and none above the target
+    for height in 2..=10_u32 {
+        assert!(
+            state
+                .light_store
+                .get_trusted_or_verified(height.into())
+                .is_some(),
+            "missing verified block at height {height}"
+        );
+    }
+    assert_eq!(
+        state.light_store.highest_trusted_or_verified(),
+        Some(verified)
+    );
+
+    // Targets which were already verified are served from the store
+    let stored = light_client
+        .verify_to_target(5_u32.into(), &mut state)
+        .unwrap();
+    assert_eq!(stored.height(), 5_u32.into());
+    assert_eq!(
+        state.light_store.highest_trusted_or_verified(),
+        Some(chain_blocks(16).swap_remove(9))
+    );
+}
+
+/// Builds a light client instance over a store holding the given verified block,
+/// resuming from it or bootstrapping from the block at `anchor_height`.
+fn resume_or_bootstrap(stored: LightBlock, anchor_height: u64) -> Instance {
+    let blocks = chain_blocks(60);
+    let anchor = &blocks[anchor_height as usize - 1];
+
+    let mut light_store = MemoryStore::new();
+    light_store.insert(stored, Status::Verified);
+
+    let options = Options {
+        trust_threshold: Default::default(),
+        trusting_period: Duration::from_secs(60),
+        clock_drift: Duration::from_secs(10),
+    };
+
+    LightClientBuilder::custom(
+        default_peer_id(),
+        options,
+        Box::new(light_store),
+        Box::new(MockIo::new(blocks.clone())),
+        Box::new(FixedClock::new(get_time(100).unwrap())),
+        Box::new(ProdVerifier::default()),
+        Box::new(scheduler::basic_bisecting_schedule),
+        Box::new(ProdPredicates),
+    )
+    .trust_from_store_or_primary_at(anchor.height(), anchor.signed_header.header.hash())
+    .unwrap()
+    .build()
+}
+
+#[test]
+fn builder_resumes_from_store_within_trusting_period() {
+    let instance = resume_or_bootstrap(chain_blocks(60).swap_remove(44), 50);
+    assert_eq!(
+        instance.latest_trusted().map(|lb| lb.height()),
+        Some(45_u32.into())
+    );
+}
+
+#[test]
+fn builder_bootstraps_when_store_is_past_trusting_period() {
+    let instance = resume_or_bootstrap(chain_blocks(60).swap_remove(1), 50);
+    assert_eq!(
+        instance.latest_trusted().map(|lb| lb.height()),
+        Some(50_u32.into())
+    );
+}
+
+#[test]
+fn init_from_trusted_hash_with_matching_hash() {
+    let blocks = chain_blocks(3);
+    let expected = blocks[1].clone();
+    let io = MockIo::new(blocks);
+
+    let trusted_state = LightClient::init_from_trusted_hash(
+        expected.height(),
+        expected.signed_header.header.hash(),
+        &io,
+    )
+    .unwrap();
+
+    assert_eq!(trusted_state, expected);
+}
+
+#[test]
+fn init_from_trusted_hash_aborts_on_hash_mismatch() {
+    let blocks = chain_blocks(3);
+    let other_hash = blocks[2].signed_header.header.hash();
+    let io = MockIo::new(blocks);
+
+    let result = LightClient::init_from_trusted_hash(2_u32.into(), other_hash, &io);
+
+    match result.unwrap_err().detail() {
+        ErrorDetail::TrustedHashMismatch(e) => assert_eq!(e.given, other_hash),
+        e => panic!("expected TrustedHashMismatch error, got: {e:?}"),
+    }
+}
+
+#[test]
+fn init_from_trusted_hash_rejects_mismatched_validator_set() {
+    let mut blocks = chain_blocks(3);
+    let hash = blocks[1].signed_header.header.hash();
+    // Drop a validator from the set committed to in the header
+    blocks[1].validators = tendermint::validator::Set::without_proposer(
+        blocks[1].validators.validators()[1..].to_vec(),
+    );
+    let io = MockIo::new(blocks);
+
+    let result = LightClient::init_from_trusted_hash(2_u32.into(), hash, &io);
+
+    match result.unwrap_err().detail() {
+        ErrorDetail::InvalidLightBlock(e) => assert!(matches!(
+            e.source,
+            VerificationErrorDetail::InvalidValidatorSet(_)
+        )),
+        e => panic!("expected InvalidLightBlock error, got: {e:?}"),
+    }
+}
+
+/// Light blocks 2 to 6 of the default testgen chain, the third of which is
+/// invalid, along with the trusted light block at height 1.
+fn batch_with_third_block_invalid() -> (LightBlock, Vec<LightBlock>) {
+    let mut blocks = chain_blocks(6);
+    let trusted = blocks.remove(0);
+    // Drop a validator from the set committed to in the header at height 4
+    blocks[2].validators = tendermint::validator::Set::without_proposer(
+        blocks[2].validators.validators()[1..].to_vec(),
+    );
+    (trusted, blocks)
+}
+
+#[test]
+fn verify_batch_aborts_on_first_failure() {
+    let (light_client, _) = make(1, get_time(30).unwrap(), ProdVerifier::default());
+    let (trusted, blocks) = batch_with_third_block_invalid();
+
+    let result = light_client.verify_batch(&trusted, &blocks, BatchMode::AbortOnFirstFailure);
+
+    assert!(!result.is_success());
+    assert_eq!(result.verdicts.len(), 3);
+    assert_eq!(result.verdicts[..2], [Verdict::Success, Verdict::Success]);
+    assert!(matches!(
+        result.verdicts[2],
+        Verdict::Invalid(VerificationErrorDetail::InvalidValidatorSet(_))
+    ));
+    assert_eq!(result.trusted, blocks[1]);
+}
+
+#[test]
+fn verify_batch_collects_all_verdicts() {
+    let (light_client, _) = make(1, get_time(30).unwrap(), ProdVerifier::default());
+    let (trusted, blocks) = batch_with_third_block_invalid();
+
+    let result = light_client.verify_batch(&trusted, &blocks, BatchMode::CollectAll);
+
+    assert!(!result.is_success());
+    assert_eq!(result.verdicts.len(), 5);
+    for (i, verdict) in result.verdicts.iter().enumerate() {
+        if i == 2 {
+            assert!(matches!(verdict, Verdict::Invalid(_)), "{verdict:?}");
+        } else {
+            assert_eq!(*verdict, Verdict::Success, "block {i}");
+        }
+    }
+    // The blocks after the invalid one are verified against the block before it
+    assert_eq!(result.trusted, blocks[4]);
+}
+
+/// A light block of the default testgen chain, whose time is `height` seconds
+/// after the epoch.
+fn light_block_at(height: u64) -> LightBlock {
+    let lb = TestgenLightBlock::new_default(height).generate().unwrap();
+    LightBlock {
+        signed_header: lb.signed_header,
+        validators: lb.validators,
+        next_validators: lb.next_validators,
+        provider: lb.provider,
+    }
+}
+
+fn verify_single_at(untrusted: LightBlock, now: Time) -> Result<LightBlock, Verdict> {
+    verify_single(
+        light_block_at(1),
+        untrusted,
+        Default::default(),
+        Duration::from_secs(2 * 3600),
+        Duration::from_secs(0),
+        now,
+    )
+}
+
+fn assert_header_from_the_future(
+    result: Result<LightBlock, Verdict>,
+    header_time: Time,
+    now: Time,
+) {
+    match result {
+        Err(Verdict::Invalid(VerificationErrorDetail::HeaderFromTheFuture(e))) => {
+            assert_eq!(e.header_time, header_time);
+            assert_eq!(e.now, now);
+        },
+        result => panic!("expected HeaderFromTheFuture, got {result:?}"),
+    }
+}
+
+#[test]
+fn verify_single_rejects_header_one_second_in_the_future() {
+    let untrusted = light_block_at(2);
+    let header_time = untrusted.time();
+
+    let now = get_time(1).unwrap();
+    assert_header_from_the_future(verify_single_at(untrusted.clone(), now), header_time, now);
+
+    assert!(verify_single_at(untrusted, get_time(3).unwrap()).is_ok());
+}
+
+#[test]
+fn verify_single_rejects_header_one_hour_in_the_future() {
+    let untrusted = light_block_at(1 + 3600);
+    let header_time = untrusted.time();
+
+    let now = get_time(1).unwrap();
+    assert_header_from_the_future(verify_single_at(untrusted.clone(), now), header_time, now);
+
+    assert!(verify_single_at(untrusted, get_time(3602).unwrap()).is_ok());
+}
@@ -15,8 +15,10 @@ mod mbt {
     };
     use tendermint_testgen::{
         apalache::*,
+        generate_single_step,
         jsonatr::*,
         light_block::{default_peer_id, TmLightBlock},
+        single_step,
         validator::generate_validators,
         Command, Generator, LightBlock as TestgenLightBlock, TestEnv, Tester, Validator, Vote,
     };
@@ -65,8 +67,14 @@ mod mbt {
         description: String,
         initial: Initial,
         input: Vec<BlockVerdict>,
+        /// The clock drift to verify the input blocks with;
+        /// `DEFAULT_CLOCK_DRIFT` if absent
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        clock_drift: Option<tendermint::Duration>,
     }
 
+    const DEFAULT_CLOCK_DRIFT: Duration = Duration::from_secs(0);
+
     /// A LiteBlock together with the time when it's being checked, and the expected verdict
     #[derive(Serialize, Deserialize, Clone, Debug)]
     pub struct BlockVerdict {
@@ -525,7 +533,7 @@ mod mbt {
             tc.initial.next_validator_set,
             default_peer_id(),
         );
-        let clock_drift = Duration::from_secs(0);
+        let clock_drift = tc.clock_drift.map_or(DEFAULT_CLOCK_DRIFT, Into::into);
         let trusting_period: Duration = tc.initial.trusting_period.into();
 
         for (i, input) in tc.input.iter().enumerate() {
@@ -549,47 +557,40 @@ mod mbt {
                 // ------------------->
                 // Below is a temporary work around to get rid of bug-gy validator sorting
                 // which was making all the tests fail
-                let current_vals = input.block.validators.clone();
-                let current_resorted = Set::without_proposer(current_vals.validators().to_vec());
-
-                let current_next_vals = input.block.next_validators.clone();
-                let current_next_resorted =
-                    Set::without_proposer(current_next_vals.validators().to_vec());
-
-                let mut mutated_block = input.block.clone();
-                mutated_block.validators = current_resorted;
-                mutated_block.next_validators = current_next_resorted;
+                let mutated_block = LightBlock::new(
+                    input.block.signed_header.clone(),
+                    Set::without_proposer(input.block.validators.validators().to_vec()),
+                    Set::without_proposer(input.block.next_validators.validators().to_vec()),
+                    input.block.provider,
+                );
                 // ------------------->
 
                 let now = input.now;
-                match verify_single(
-                    latest_trusted.clone(),
-                    mutated_block.clone(),
+                match verify_single_ref(
+                    &latest_trusted,
+                    &mutated_block,
                     TrustThreshold::default(),
                     trusting_period,
                     clock_drift,
                     now,
                 ) {
-                    Ok(new_state) => {
+                    Ok(_) => {
                         assert_eq!(input.verdict, LiteVerdict::Success);
-                        let expected_state: LightBlock = mutated_block.clone();
-                        assert_eq!(new_state, expected_state);
-                        latest_trusted = LightBlock::new(
-                            new_state.signed_header,
-                            new_state.validators,
-                            new_state.next_validators,
-                            new_state.provider,
-                        );
+                        latest_trusted = mutated_block;
                     },
                     Err(e) => {
                         output_env.logln(&format!("      > lite: {e:?}"));
                         match e {
-                            Verdict::Invalid(_) => assert_eq!(input.verdict, LiteVerdict::Invalid),
+                            // The model deems a block invalid when the trusted header
+                            // is outside of the trusting period, see `ValidAndVerifiedPre`.
+                            Verdict::Expired(_) | Verdict::Invalid(_) => {
+                                assert_eq!(input.verdict, LiteVerdict::Invalid)
+                            },
                             Verdict::NotEnoughTrust(_) => {
                                 assert_eq!(input.verdict, LiteVerdict::NotEnoughTrust)
                             },
                             Verdict::Success => {
-                                panic!("verify_single() returned error with Verdict::Success")
+                                panic!("verify_single_ref() returned error with Verdict::Success")
                             },
                         }
                     },
@@ -710,17 +711,11 @@ mod mbt {
     }
 
     fn model_based_test_batch(batch: ApalacheTestBatch) -> Vec<(String, String)> {
-        let mut res = Vec::new();
-        for test in batch.tests {
-            let tc = ApalacheTestCase {
-                model: batch.model.clone(),
-                test: test.clone(),
-                length: batch.length,
-                timeout: batch.timeout,
-            };
-            res.push((test.clone(), serde_json::to_string(&tc).unwrap()));
-        }
-        res
+        batch
+            .test_cases()
+            .into_iter()
+            .map(|tc| (tc.test.clone(), serde_json::to_string(&tc).unwrap()))
+            .collect()
     }
 
     const TEST_DIR: &str = "./tests/support/model_based";
@@ -734,4 +729,60 @@ mod mbt {
         tester.run_foreach_in_dir("");
         tester.finalize();
     }
+
+    #[test]
+    fn generated_single_step_test_round_trips() {
+        let at = |secs| tendermint::Time::from_unix_timestamp(secs, 0).unwrap();
+        let block_1 = TestgenLightBlock::new_default(1);
+        let block_2 = block_1.next();
+        let block_3 = block_2.next();
+
+        let initial = single_step::Initial {
+            light_block: block_1,
+            trusting_period: Duration::from_secs(10),
+            now: at(1),
+        };
+        let inputs = [
+            single_step::Input {
+                light_block: block_2,
+                now: at(3),
+                verdict: single_step::Verdict::Success,
+            },
+            single_step::Input {
+                light_block: block_3,
+                now: at(100),
+                verdict: single_step::Verdict::Invalid,
+            },
+        ];
+        let json = generate_single_step("generated", &initial, &inputs).unwrap();
+
+        let tc: SingleStepTestCase = serde_json::from_str(&json).unwrap();
+        assert_eq!(tc.description, "generated");
+        assert_eq!(tc.input[0].verdict, LiteVerdict::Success);
+        assert_eq!(tc.input[1].verdict, LiteVerdict::Invalid);
+
+        let dir = tempfile::tempdir().unwrap();
+        let env = TestEnv::new(dir.path().to_str().unwrap()).unwrap();
+        single_step_test(tc, &env, &env, &env);
+    }
+
+    #[test]
+    fn clock_drift_overrides_default() {
+        let env = TestEnv::new(TEST_DIR).unwrap();
+        let mut tc: SingleStepTestCase = env
+            .parse_file("single_step/TestHeaderFromFutureWithinClockDrift.json")
+            .unwrap();
+        assert_eq!(tc.clock_drift, Some(Duration::from_secs(5).into()));
+
+        let dir = tempfile::tempdir().unwrap();
+        let output_env = TestEnv::new(dir.path().to_str().unwrap()).unwrap();
+        single_step_test(tc.clone(), &output_env, &output_env, &output_env);
+
+        // With the default drift, the headers are from the future
+        tc.clock_drift = None;
+        for input in tc.input.iter_mut() {
+            input.verdict = LiteVerdict::Invalid;
+        }
+        single_step_test(tc, &output_env, &output_env, &output_env);
+    }
 }
@@ -185,7 +185,7 @@ pub struct Commit {
     #[prost(message, optional, tag = "3")]
     pub block_id: ::core::option::Option<BlockId>,
     #[prost(message, repeated, tag = "4")]
-    #[serde(with = "crate::serializers::nullable")]
+    #[serde(alias = "precommits", with = "crate::serializers::nullable")]
     pub signatures: ::prost::alloc::vec::Vec<CommitSig>,
 }
 /// CommitSig is a part of the Vote included in a Commit.
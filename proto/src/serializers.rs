@@ -54,6 +54,7 @@
 #![allow(dead_code)]
 
 pub mod allow_null;
+pub mod base64;
 pub mod bytes;
 pub mod evidence;
 pub mod from_str;
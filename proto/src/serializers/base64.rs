@@ -0,0 +1,48 @@
+//! Base64 encoding and decoding which never panics on malformed input.
+//!
+//! The decoder of `subtle-encoding` panics when given padding in unexpected
+//! positions, so inputs with misplaced padding are rejected before decoding.
+
+use subtle_encoding::Error;
+
+use crate::prelude::*;
+
+pub use subtle_encoding::base64::encode;
+
+/// Decode a base64 string, returning an error on malformed input.
+pub fn decode<T: AsRef<[u8]>>(encoded: T) -> Result<Vec<u8>, Error> {
+    let encoded = encoded.as_ref();
+    let body_len = encoded
+        .iter()
+        .rposition(|&c| c != b'=')
+        .map_or(0, |i| i + 1);
+    let padding = encoded.len() - body_len;
+
+    if encoded[..body_len].contains(&b'=')
+        || (padding > 0 && (padding > 2 || encoded.len() % 4 != 0))
+    {
+        return Err(Error::PaddingInvalid);
+    }
+
+    subtle_encoding::base64::decode(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_valid_input() {
+        assert_eq!(decode("").unwrap(), b"");
+        assert_eq!(decode("QQ==").unwrap(), b"A");
+        assert_eq!(decode("QUI=").unwrap(), b"AB");
+        assert_eq!(decode("QUJD").unwrap(), b"ABC");
+    }
+
+    #[test]
+    fn rejects_misplaced_padding() {
+        for encoded in ["=", "==", "=QQ", "QQ=", "Q=Q=", "QUJD=", "QUJD===", "QQ==#"] {
+            assert!(decode(encoded).is_err(), "{encoded:?}");
+        }
+    }
+}
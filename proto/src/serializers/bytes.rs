@@ -33,9 +33,8 @@ pub mod hexstring {
 /// Serialize into base64string, deserialize from base64string
 pub mod base64string {
     use serde::{Deserialize, Deserializer, Serializer};
-    use subtle_encoding::base64;
 
-    use crate::prelude::*;
+    use crate::{prelude::*, serializers::base64};
 
     /// Deserialize base64string into `Vec<u8>`
     pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -73,9 +72,8 @@ pub mod base64string {
 /// Serialize into and deserialize from a sequence of _base64string_.
 pub mod vec_base64string {
     use serde::{Deserialize, Deserializer, Serializer};
-    use subtle_encoding::base64;
 
-    use crate::prelude::*;
+    use crate::{prelude::*, serializers::base64};
 
     /// Deserialize array into `Vec<Vec<u8>>`
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
@@ -108,9 +106,8 @@ pub mod vec_base64string {
 /// Serialize into and deserialize from an optional _base64string_.
 pub mod option_base64string {
     use serde::{Deserialize, Deserializer, Serializer};
-    use subtle_encoding::base64;
 
-    use crate::prelude::*;
+    use crate::{prelude::*, serializers::base64};
 
     /// Deserialize `Option<base64string>` into `Vec<u8>` or null
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
@@ -1,8 +1,7 @@
 //! Serialize/deserialize `Vec<Vec<u8>>` type from and into transactions (Base64String array).
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use subtle_encoding::base64;
 
-use crate::prelude::*;
+use crate::{prelude::*, serializers::base64};
 
 /// Deserialize transactions into `Vec<Vec<u8>>`
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
@@ -1,4 +1,6 @@
-use tendermint_proto::v0_38::types::{BlockId as RawBlockId, PartSetHeader as RawPartSetHeader};
+use tendermint_proto::v0_38::types::{
+    BlockId as RawBlockId, BlockIdFlag, PartSetHeader as RawPartSetHeader,
+};
 use tendermint_proto::Protobuf;
 
 impl Protobuf<RawBlockId> for BlockId {}
@@ -107,3 +109,19 @@ pub fn protobuf_struct_conveniences_example() {
     let new_domain_type = BlockId::decode_length_delimited_vec(&wire).unwrap();
     assert_eq!(my_domain_type, new_domain_type);
 }
+
+#[test]
+pub fn block_id_flag_round_trip() {
+    for flag in [
+        BlockIdFlag::Unknown,
+        BlockIdFlag::Absent,
+        BlockIdFlag::Commit,
+        BlockIdFlag::Nil,
+    ] {
+        assert_eq!(BlockIdFlag::try_from(flag as i32).unwrap(), flag);
+    }
+    assert_eq!(BlockIdFlag::Commit as i32, 2);
+
+    assert!(BlockIdFlag::try_from(7).is_err());
+    assert!(!BlockIdFlag::is_valid(7));
+}
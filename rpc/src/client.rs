@@ -6,7 +6,7 @@ pub use compat::CompatMode;
 #[cfg(any(feature = "http-client", feature = "websocket-client"))]
 mod subscription;
 #[cfg(any(feature = "http-client", feature = "websocket-client"))]
-pub use subscription::{Subscription, SubscriptionClient};
+pub use subscription::{Subscription, SubscriptionClient, TrackedEvent};
 
 #[cfg(any(feature = "http-client", feature = "websocket-client"))]
 pub mod sync;
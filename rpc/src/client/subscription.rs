@@ -1,19 +1,21 @@
 //! Subscription- and subscription management-related functionality.
 
-use core::pin::Pin;
+use core::{pin::Pin, time::Duration};
 
 use async_trait::async_trait;
 use futures::{
+    future, stream,
     task::{Context, Poll},
-    Stream,
+    Stream, StreamExt,
 };
 use pin_project::pin_project;
+use tendermint::block;
 
 use crate::{
     client::sync::{ChannelRx, ChannelTx},
-    event::Event,
+    event::{Event, EventData},
     prelude::*,
-    query::Query,
+    query::{EventType, Query},
     Error,
 };
 
@@ -25,8 +27,25 @@ pub trait SubscriptionClient {
     /// `/subscribe`: subscribe to receive events produced by the given query.
     async fn subscribe(&self, query: Query) -> Result<Subscription, Error>;
 
+    /// `/subscribe`: subscribe to receive events produced by the given query,
+    /// failing with [`Error::timeout`] if the subscription is not confirmed
+    /// by the remote endpoint within the given duration.
+    ///
+    /// On timeout, no subscription to the query is left registered with the
+    /// client, and the remote endpoint is asked to unsubscribe from the query
+    /// should it confirm the subscription later on.
+    async fn subscribe_timeout(
+        &self,
+        query: Query,
+        timeout: Duration,
+    ) -> Result<Subscription, Error>;
+
     /// `/unsubscribe`: unsubscribe from events relating to the given query.
     ///
+    /// All [`Subscription`]s to the query are terminated, ending their event
+    /// streams, and the method returns once the remote endpoint has
+    /// acknowledged the request.
+    ///
     /// This method is particularly useful when you want to terminate multiple
     /// [`Subscription`]s to the same [`Query`] simultaneously, or if you've
     /// joined multiple `Subscription`s together using [`select_all`] and you
@@ -36,11 +55,34 @@ pub trait SubscriptionClient {
     /// [`select_all`]: https://docs.rs/futures/*/futures/stream/fn.select_all.html
     async fn unsubscribe(&self, query: Query) -> Result<(), Error>;
 
+    /// The queries of all the subscriptions currently registered with this
+    /// client, each listed once regardless of how many [`Subscription`]s
+    /// share it.
+    ///
+    /// This is mainly useful to detect subscriptions which were leaked, e.g.
+    /// after reconnecting.
+    async fn active_subscriptions(&self) -> Result<Vec<String>, Error>;
+
     /// Subscription clients will usually have long-running underlying
     /// transports that will need to be closed at some point.
     fn close(self) -> Result<(), Error>;
 }
 
+/// An item of a [`Subscription`] tracking gaps in block heights, see
+/// [`Subscription::track_gaps`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackedEvent {
+    /// An event received from the subscription.
+    Event(Event),
+    /// The `NewBlock` events for the heights `from` to `to`, inclusive, were
+    /// not received before the next one.
+    Gap {
+        from: block::Height,
+        to: block::Height,
+    },
+}
+
 pub(crate) type SubscriptionTx = ChannelTx<Result<Event, Error>>;
 pub(crate) type SubscriptionRx = ChannelRx<Result<Event, Error>>;
 
@@ -102,4 +144,187 @@ impl Subscription {
     pub fn query(&self) -> &Query {
         &self.query
     }
+
+    /// Transform this subscription into a stream of the headers of the blocks
+    /// carried by `NewBlock` events.
+    ///
+    /// The rest of each block, including its transactions, is dropped as soon
+    /// as the event is received. Events which do not carry a block are skipped,
+    /// while errors are passed through.
+    ///
+    /// Note that a `NewBlock` event does not carry the commit for its block,
+    /// which only comes with the next block, so that no `SignedHeader` can be
+    /// produced from a single event.
+    pub fn map_headers(self) -> impl Stream<Item = Result<block::Header, Error>> {
+        self.filter_map(|res| {
+            future::ready(match res {
+                Ok(ev) => match ev.data {
+                    EventData::NewBlock { block, .. } | EventData::LegacyNewBlock { block, .. } => {
+                        block.map(|block| Ok(block.header))
+                    },
+                    _ => None,
+                },
+                Err(e) => Some(Err(e)),
+            })
+        })
+    }
+
+    /// Transform this subscription into a stream of only the events of the
+    /// given type, as reported by [`Event::event_type`].
+    ///
+    /// Errors are passed through.
+    pub fn filter_type(self, event_type: EventType) -> impl Stream<Item = Result<Event, Error>> {
+        self.filter(move |res| {
+            future::ready(match res {
+                Ok(ev) => ev.event_type().as_ref() == Some(&event_type),
+                Err(_) => true,
+            })
+        })
+    }
+
+    /// Transform this subscription into a stream reporting the block heights
+    /// skipped between consecutive `NewBlock` events.
+    ///
+    /// `last_seen` is the height of the last block delivered before this
+    /// subscription was created, e.g. by a subscription to the same query
+    /// which was dropped on losing the connection. A [`TrackedEvent::Gap`] is
+    /// emitted right before the first block following missing heights, so
+    /// that they can be fetched through RPC.
+    pub fn track_gaps(
+        self,
+        last_seen: Option<block::Height>,
+    ) -> impl Stream<Item = Result<TrackedEvent, Error>> {
+        self.scan(last_seen, |last_seen, res| {
+            let mut items = Vec::with_capacity(2);
+            if let Ok(ev) = &res {
+                if let Some(block) = ev.data.as_new_block() {
+                    let height = block.header.height;
+                    if let Some(last) = last_seen {
+                        if height.value() > last.value() + 1 {
+                            items.push(Ok(TrackedEvent::Gap {
+                                from: last.increment(),
+                                to: (height.value() - 1).try_into().unwrap(),
+                            }));
+                        }
+                    }
+                    *last_seen = Some(height);
+                }
+            }
+            items.push(res.map(TrackedEvent::Event));
+            future::ready(Some(stream::iter(items)))
+        })
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::sync::unbounded, event::v0_37::DeEvent, Response};
+
+    fn read_event(fixture: &str) -> Event {
+        DeEvent::from_string(fixture).unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn map_headers_yields_block_headers() {
+        let new_block = read_event(include_str!(
+            "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_newblock_0.json"
+        ));
+        let tx = read_event(include_str!(
+            "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_txs_0.json"
+        ));
+        let expected = new_block.data.as_new_block().unwrap().header.clone();
+
+        let (event_tx, event_rx) = unbounded();
+        let subs = Subscription::new("1".to_string(), EventType::NewBlock.into(), event_rx);
+        event_tx.send(Ok(tx)).unwrap();
+        event_tx.send(Ok(new_block)).unwrap();
+        event_tx
+            .send(Err(Error::client_internal("failed".to_string())))
+            .unwrap();
+        drop(event_tx);
+
+        let results = subs.map_headers().collect::<Vec<_>>().await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &expected);
+        assert!(results[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn filter_type_yields_matching_events() {
+        let new_block = read_event(include_str!(
+            "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_newblock_0.json"
+        ));
+        let txs = [
+            read_event(include_str!(
+                "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_txs_0.json"
+            )),
+            read_event(include_str!(
+                "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_txs_1.json"
+            )),
+        ];
+
+        let (event_tx, event_rx) = unbounded();
+        let subs = Subscription::new("1".to_string(), EventType::Tx.into(), event_rx);
+        event_tx.send(Ok(new_block.clone())).unwrap();
+        event_tx.send(Ok(txs[0].clone())).unwrap();
+        event_tx.send(Ok(new_block)).unwrap();
+        event_tx.send(Ok(txs[1].clone())).unwrap();
+        drop(event_tx);
+
+        let results = subs
+            .filter_type(EventType::Tx)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(results, txs);
+    }
+
+    fn new_block_at(height: u64) -> Event {
+        let mut ev = read_event(include_str!(
+            "../../tests/kvstore_fixtures/v0_37/incoming/subscribe_newblock_0.json"
+        ));
+        match &mut ev.data {
+            EventData::LegacyNewBlock { block, .. } | EventData::NewBlock { block, .. } => {
+                block.as_mut().unwrap().header.height = height.try_into().unwrap();
+            },
+            _ => unreachable!(),
+        }
+        ev
+    }
+
+    fn heights(results: &[Result<TrackedEvent, Error>]) -> Vec<Result<u64, (u64, u64)>> {
+        results
+            .iter()
+            .map(|res| match res.as_ref().unwrap() {
+                TrackedEvent::Event(ev) => {
+                    Ok(ev.data.as_new_block().unwrap().header.height.value())
+                },
+                TrackedEvent::Gap { from, to } => Err((from.value(), to.value())),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn track_gaps_reports_heights_missed_while_reconnecting() {
+        let (event_tx, event_rx) = unbounded();
+        let subs = Subscription::new("1".to_string(), EventType::NewBlock.into(), event_rx);
+        event_tx.send(Ok(new_block_at(1))).unwrap();
+        event_tx.send(Ok(new_block_at(2))).unwrap();
+        drop(event_tx);
+
+        let results = subs.track_gaps(None).collect::<Vec<_>>().await;
+        assert_eq!(heights(&results), vec![Ok(1), Ok(2)]);
+
+        // Heights 3 to 5 are produced while the connection is down.
+        let (event_tx, event_rx) = unbounded();
+        let subs = Subscription::new("2".to_string(), EventType::NewBlock.into(), event_rx);
+        event_tx.send(Ok(new_block_at(6))).unwrap();
+        event_tx.send(Ok(new_block_at(7))).unwrap();
+        drop(event_tx);
+
+        let results = subs.track_gaps(Some(2u32.into())).collect::<Vec<_>>().await;
+        assert_eq!(heights(&results), vec![Err((3, 5)), Ok(6), Ok(7)]);
+    }
 }
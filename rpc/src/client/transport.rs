@@ -1,5 +1,8 @@
 //! Tendermint RPC client implementations for different transports.
 
+#[cfg(any(feature = "http-client", feature = "websocket-client"))]
+use crate::prelude::*;
+
 mod auth;
 pub mod mock;
 mod router;
@@ -22,6 +25,34 @@ macro_rules! perform_with_compat {
     }};
 }
 
+/// Validate the custom HTTP headers supplied to a client builder.
+///
+/// Both transports send these headers in addition to the ones derived from
+/// the client's URL, and let them replace the derived headers of the same
+/// name: a custom `Authorization` header takes precedence over the basic
+/// authentication credentials in the URL.
+#[cfg(any(feature = "http-client", feature = "websocket-client"))]
+fn parse_headers<N, V>(headers: &[(String, String)]) -> Result<Vec<(N, V)>, crate::Error>
+where
+    N: core::str::FromStr,
+    N::Err: core::fmt::Display,
+    V: core::str::FromStr,
+    V::Err: core::fmt::Display,
+{
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let parsed_name = name.parse().map_err(|e| {
+                crate::Error::invalid_params(format!("invalid HTTP header name {name}: {e}"))
+            })?;
+            let parsed_value = value.parse().map_err(|e| {
+                crate::Error::invalid_params(format!("invalid value for HTTP header {name}: {e}"))
+            })?;
+            Ok((parsed_name, parsed_value))
+        })
+        .collect()
+}
+
 #[cfg(feature = "http-client")]
 pub mod http;
 #[cfg(feature = "websocket-client")]
@@ -1,6 +1,7 @@
 //! Mock client implementation for use in testing.
 
 use alloc::collections::BTreeMap as HashMap;
+use core::time::Duration;
 
 use async_trait::async_trait;
 
@@ -114,6 +115,16 @@ impl<M: MockRequestMatcher> SubscriptionClient for MockClient<M> {
         Ok(Subscription::new(id, query, subs_rx))
     }
 
+    // Subscriptions to a mock client are confirmed immediately, so the
+    // timeout never elapses.
+    async fn subscribe_timeout(
+        &self,
+        query: Query,
+        _timeout: Duration,
+    ) -> Result<Subscription, Error> {
+        self.subscribe(query).await
+    }
+
     async fn unsubscribe(&self, query: Query) -> Result<(), Error> {
         let (result_tx, mut result_rx) = unbounded();
         self.driver_tx
@@ -121,6 +132,13 @@ impl<M: MockRequestMatcher> SubscriptionClient for MockClient<M> {
         result_rx.recv().await.unwrap()
     }
 
+    async fn active_subscriptions(&self) -> Result<Vec<String>, Error> {
+        let (result_tx, mut result_rx) = unbounded();
+        self.driver_tx
+            .send(DriverCommand::ActiveSubscriptions { result_tx })?;
+        Ok(result_rx.recv().await.unwrap())
+    }
+
     fn close(self) -> Result<(), Error> {
         Ok(())
     }
@@ -138,6 +156,9 @@ pub enum DriverCommand {
         query: Query,
         result_tx: ChannelTx<Result<(), Error>>,
     },
+    ActiveSubscriptions {
+        result_tx: ChannelTx<Vec<String>>,
+    },
     Publish(Box<Event>),
     Terminate,
 }
@@ -166,6 +187,9 @@ impl MockClientDriver {
                     DriverCommand::Unsubscribe { query, result_tx } => {
                         self.unsubscribe(query, result_tx);
                     }
+                    DriverCommand::ActiveSubscriptions { result_tx } => {
+                        result_tx.send(self.router.queries()).unwrap();
+                    }
                     DriverCommand::Publish(event) => self.publish(*event),
                     DriverCommand::Terminate => return Ok(()),
                 }
@@ -262,6 +286,32 @@ mod test {
         .unwrap()
     }
 
+    #[tokio::test]
+    async fn active_subscriptions() {
+        let (client, driver) = MockClient::new(MockRequestMethodMatcher::default());
+        let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+        let new_block = Query::from(EventType::NewBlock);
+        let tx = Query::from(EventType::Tx);
+        let _new_block_subs = client.subscribe(new_block.clone()).await.unwrap();
+        let _tx_subs = client.subscribe(tx.clone()).await.unwrap();
+
+        let mut queries = client.active_subscriptions().await.unwrap();
+        queries.sort();
+        let mut expected = vec![new_block.to_string(), tx.to_string()];
+        expected.sort();
+        assert_eq!(queries, expected);
+
+        client.unsubscribe(new_block).await.unwrap();
+        assert_eq!(
+            client.active_subscriptions().await.unwrap(),
+            vec![tx.to_string()]
+        );
+
+        client.close();
+        driver_hdl.await.unwrap().unwrap();
+    }
+
     mod v0_34 {
         use super::*;
         use crate::event::v0_34::DeEvent;
@@ -396,5 +446,66 @@ mod test {
             client.close();
             driver_hdl.await.unwrap().unwrap();
         }
+
+        /// Records the JSON of every request it is given and responds with
+        /// the same response to all of them.
+        struct RecordingMatcher {
+            requests: std::sync::Mutex<Vec<serde_json::Value>>,
+            response: String,
+        }
+
+        impl MockRequestMatcher for RecordingMatcher {
+            fn response_for<R, S>(&self, request: R) -> Option<Result<R::Response, Error>>
+            where
+                R: Request<S>,
+                S: Dialect,
+            {
+                let json = serde_json::from_str(&request.into_json()).unwrap();
+                self.requests.lock().unwrap().push(json);
+                Some(R::Response::from_string(&self.response))
+            }
+        }
+
+        #[tokio::test]
+        async fn broadcast_evidence() {
+            // Only the v0.34 fixtures include a block with evidence
+            let fixture = read_json_fixture("v0_34", "block_search_evidence").await;
+            let blocks = crate::endpoint::block_search::Response::from_string(fixture)
+                .unwrap()
+                .blocks;
+            let evidence = blocks[0].block.evidence.iter().next().unwrap().clone();
+
+            let matcher = RecordingMatcher {
+                requests: Default::default(),
+                response: r#"{
+                    "jsonrpc": "2.0",
+                    "id": "",
+                    "result": { "hash": "ZXZpZGVuY2UgaGFzaCBpcyAzMiBieXRlcyBsb25nISE=" }
+                }"#
+                .to_owned(),
+            };
+            let (client, driver) = MockClient::new(matcher);
+            let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+            let response = client.broadcast_evidence(evidence).await.unwrap();
+            assert_eq!(
+                response.hash.as_bytes(),
+                b"evidence hash is 32 bytes long!!"
+            );
+
+            let requests = client.matcher.requests.lock().unwrap().clone();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0]["method"], "broadcast_evidence");
+            let evidence = &requests[0]["params"]["evidence"];
+            assert_eq!(evidence["type"], "tendermint/DuplicateVoteEvidence");
+            assert!(evidence["value"]["vote_a"].is_object());
+            assert!(evidence["value"]["vote_b"].is_object());
+            assert!(evidence["value"]["TotalVotingPower"].is_string());
+            assert!(evidence["value"]["ValidatorPower"].is_string());
+            assert!(evidence["value"]["Timestamp"].is_string());
+
+            client.close();
+            driver_hdl.await.unwrap().unwrap();
+        }
     }
 }
@@ -104,6 +104,15 @@ impl SubscriptionRouter {
         subs_for_query.insert(id.to_string(), tx);
     }
 
+    /// Returns the queries for which there is at least one subscription.
+    pub fn queries(&self) -> Vec<SubscriptionQuery> {
+        self.subscriptions
+            .iter()
+            .filter(|(_, subs_for_query)| !subs_for_query.is_empty())
+            .map(|(query, _)| query.clone())
+            .collect()
+    }
+
     /// Removes all the subscriptions relating to the given query.
     pub fn remove_by_query(&mut self, query: impl ToString) -> usize {
         self.subscriptions
@@ -178,6 +187,29 @@ mod test {
         }
     }
 
+    #[cfg(feature = "websocket-client")]
+    #[tokio::test]
+    async fn router_remove_by_query_closes_subscriptions() {
+        let mut router = SubscriptionRouter::default();
+
+        let (subs1_event_tx, mut subs1_event_rx) = unbounded();
+        let (subs2_event_tx, mut subs2_event_rx) = unbounded();
+        let (subs3_event_tx, mut subs3_event_rx) = unbounded();
+        router.add(uuid_str(), "query1", subs1_event_tx);
+        router.add(uuid_str(), "query1", subs2_event_tx);
+        router.add(uuid_str(), "query2", subs3_event_tx);
+
+        assert_eq!(router.remove_by_query("query1"), 2);
+        assert_eq!(router.num_subscriptions_for_query("query1"), 0);
+        assert_eq!(router.num_subscriptions_for_query("query2"), 1);
+        assert_eq!(router.remove_by_query("query1"), 0);
+
+        // The channels of the removed subscriptions are closed
+        assert!(subs1_event_rx.recv().await.is_none());
+        assert!(subs2_event_rx.recv().await.is_none());
+        must_not_recv(&mut subs3_event_rx, 50).await;
+    }
+
     mod v0_34 {
         use super::*;
 
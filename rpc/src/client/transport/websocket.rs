@@ -7,8 +7,9 @@ use async_trait::async_trait;
 use async_tungstenite::{
     tokio::ConnectStream,
     tungstenite::{
+        error::CapacityError,
         protocol::{frame::coding::CloseCode, CloseFrame},
-        Message,
+        Error as TungsteniteError, Message,
     },
     WebSocketStream,
 };
@@ -52,6 +53,10 @@ const RECV_TIMEOUT: Duration = Duration::from_secs(RECV_TIMEOUT_SECONDS);
 // Taken from https://github.com/tendermint/tendermint/blob/309e29c245a01825fc9630103311fd04de99fa5e/rpc/jsonrpc/server/ws_handler.go#L28
 const PING_INTERVAL: Duration = Duration::from_secs((RECV_TIMEOUT_SECONDS * 9) / 10);
 
+// How long to wait for a pong after sending a ping before considering the
+// connection dead.
+const PONG_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Low-level WebSocket configuration
 pub use async_tungstenite::tungstenite::protocol::WebSocketConfig;
 
@@ -88,7 +93,12 @@ pub use async_tungstenite::tungstenite::protocol::WebSocketConfig;
 /// the Tendermint server (see [this code][tendermint-websocket-ping] for
 /// details).
 ///
-/// This is not configurable at present.
+/// If no PONG is received within 10 seconds of a PING, the connection is
+/// considered dead and the driver terminates with an error. This detects
+/// half-open connections, e.g. ones silently dropped by a NAT.
+///
+/// Both durations can be configured with [`Builder::ping_interval`] and
+/// [`Builder::pong_timeout`].
 ///
 /// ## Examples
 ///
@@ -145,6 +155,10 @@ pub struct Builder {
     url: WebSocketClientUrl,
     compat: CompatMode,
     transport_config: Option<WebSocketConfig>,
+    headers: Vec<(String, String)>,
+    ping_interval: Duration,
+    pong_timeout: Duration,
+    max_response_bytes: Option<usize>,
 }
 
 impl Builder {
@@ -162,15 +176,70 @@ impl Builder {
         self
     }
 
+    /// Send the given HTTP header with the WebSocket upgrade request, e.g. an
+    /// `Authorization: Bearer ...` header expected by an authenticating
+    /// reverse proxy.
+    ///
+    /// The header name and value are validated when the client is built.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send a ping to the remote endpoint at the given interval.
+    ///
+    /// The default is 27 seconds. The interval must not be zero, otherwise
+    /// [`Builder::build`] fails.
+    pub fn ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = interval;
+        self
+    }
+
+    /// Consider the connection dead, terminating the driver, if no pong is
+    /// received within the given duration of sending a ping.
+    ///
+    /// The default is 10 seconds.
+    pub fn pong_timeout(mut self, timeout: Duration) -> Self {
+        self.pong_timeout = timeout;
+        self
+    }
+
+    /// Reject messages larger than the given number of bytes, e.g. an
+    /// enormous `NewBlock` event, terminating the driver with a
+    /// `ResponseTooLarge` error without reading them in full.
+    ///
+    /// This overrides the message and frame size limits of the low-level
+    /// WebSocket configuration, which default to 64 MiB and 16 MiB.
+    pub fn max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+
     /// Try to create a client with the options specified for this builder.
     pub async fn build(self) -> Result<(WebSocketClient, WebSocketClientDriver), Error> {
+        if self.ping_interval.is_zero() {
+            return Err(Error::invalid_params(
+                "the WebSocket ping interval must not be zero".to_owned(),
+            ));
+        }
         let url = self.url.0;
         let compat = self.compat;
+        let headers = sealed::header_map(self.headers)?;
+        let transport_config = match self.max_response_bytes {
+            None => self.transport_config,
+            Some(limit) => {
+                let mut config = self.transport_config.unwrap_or_default();
+                config.max_message_size = Some(limit);
+                config.max_frame_size = Some(limit);
+                Some(config)
+            },
+        };
         let (inner, driver) = if url.is_secure() {
-            sealed::WebSocketClient::new_secure(url, compat, self.transport_config).await?
+            sealed::WebSocketClient::new_secure(url, compat, transport_config, headers).await?
         } else {
-            sealed::WebSocketClient::new_unsecure(url, compat, self.transport_config).await?
+            sealed::WebSocketClient::new_unsecure(url, compat, transport_config, headers).await?
         };
+        let driver = driver.keep_alive(self.ping_interval, self.pong_timeout);
 
         Ok((WebSocketClient { inner, compat }, driver))
     }
@@ -213,6 +282,10 @@ impl WebSocketClient {
             url,
             compat: Default::default(),
             transport_config: Default::default(),
+            headers: Vec::new(),
+            ping_interval: PING_INTERVAL,
+            pong_timeout: PONG_TIMEOUT,
+            max_response_bytes: None,
         }
     }
 
@@ -321,10 +394,22 @@ impl SubscriptionClient for WebSocketClient {
         self.inner.subscribe(query).await
     }
 
+    async fn subscribe_timeout(
+        &self,
+        query: Query,
+        timeout: Duration,
+    ) -> Result<Subscription, Error> {
+        self.inner.subscribe_timeout(query, timeout).await
+    }
+
     async fn unsubscribe(&self, query: Query) -> Result<(), Error> {
         self.inner.unsubscribe(query).await
     }
 
+    async fn active_subscriptions(&self) -> Result<Vec<String>, Error> {
+        self.inner.active_subscriptions().await
+    }
+
     fn close(self) -> Result<(), Error> {
         self.inner.close()
     }
@@ -399,7 +484,10 @@ impl From<WebSocketClientUrl> for Url {
 mod sealed {
     use async_tungstenite::{
         tokio::{connect_async_with_config, connect_async_with_tls_connector_and_config},
-        tungstenite::client::IntoClientRequest,
+        tungstenite::{
+            client::IntoClientRequest,
+            http::{HeaderMap, HeaderName, HeaderValue},
+        },
     };
     use tracing::debug;
 
@@ -409,7 +497,8 @@ mod sealed {
     };
     use crate::{
         client::{
-            sync::{unbounded, ChannelTx},
+            subscription::SubscriptionRx,
+            sync::{unbounded, ChannelRx, ChannelTx},
             transport::auth::authorize,
             CompatMode,
         },
@@ -420,6 +509,17 @@ mod sealed {
         utils::uuid_str,
         Error, Response, SimpleRequest, Subscription, Url,
     };
+    use tokio::time::Duration;
+
+    /// A subscription request sent to the driver, awaiting its response.
+    struct PendingSubscription {
+        /// The ID under which the subscription was requested.
+        id: String,
+        /// The channel on which subscription events will be received.
+        subscription_rx: SubscriptionRx,
+        /// The channel on which the driver responds to the request.
+        response_rx: ChannelRx<Result<(), Error>>,
+    }
 
     /// Marker for the [`AsyncTungsteniteClient`] for clients operating over
     /// unsecure connections.
@@ -456,10 +556,12 @@ mod sealed {
             url: Url,
             compat: CompatMode,
             config: Option<WebSocketConfig>,
+            headers: HeaderMap,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
             debug!("Connecting to unsecure WebSocket endpoint: {}", url);
 
-            let (stream, _response) = connect_async_with_config(url, config)
+            let request = client_request(url, headers)?;
+            let (stream, _response) = connect_async_with_config(request, config)
                 .await
                 .map_err(Error::tungstenite)?;
 
@@ -488,13 +590,15 @@ mod sealed {
             url: Url,
             compat: CompatMode,
             config: Option<WebSocketConfig>,
+            headers: HeaderMap,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
             debug!("Connecting to secure WebSocket endpoint: {}", url);
 
+            let request = client_request(url, headers)?;
             // Not supplying a connector means async_tungstenite will create the
             // connector for us.
             let (stream, _response) =
-                connect_async_with_tls_connector_and_config(url, None, config)
+                connect_async_with_tls_connector_and_config(request, None, config)
                     .await
                     .map_err(Error::tungstenite)?;
 
@@ -550,8 +654,52 @@ mod sealed {
         }
 
         pub async fn subscribe(&self, query: Query) -> Result<Subscription, Error> {
+            let PendingSubscription {
+                id,
+                subscription_rx,
+                mut response_rx,
+            } = self.send_subscribe(&query)?;
+            // Make sure our subscription request went through successfully.
+            response_rx.recv().await.ok_or_else(|| {
+                Error::client_internal("failed to hear back from WebSocket driver".to_string())
+            })??;
+            Ok(Subscription::new(id, query, subscription_rx))
+        }
+
+        pub async fn subscribe_timeout(
+            &self,
+            query: Query,
+            timeout: Duration,
+        ) -> Result<Subscription, Error> {
+            let PendingSubscription {
+                id,
+                subscription_rx,
+                mut response_rx,
+            } = self.send_subscribe(&query)?;
+            match tokio::time::timeout(timeout, response_rx.recv()).await {
+                Ok(response) => {
+                    response.ok_or_else(|| {
+                        Error::client_internal(
+                            "failed to hear back from WebSocket driver".to_string(),
+                        )
+                    })??;
+                    Ok(Subscription::new(id, query, subscription_rx))
+                },
+                Err(_) => {
+                    // If the subscription was confirmed in the meantime, the
+                    // router disconnects it once it notices that
+                    // `subscription_rx` has been dropped.
+                    self.send_cmd(DriverCommand::AbandonSubscribe(id))?;
+                    Err(Error::timeout(timeout))
+                },
+            }
+        }
+
+        // Sends a subscription request to the driver, returning the pending
+        // subscription on which the driver's response is awaited.
+        fn send_subscribe(&self, query: &Query) -> Result<PendingSubscription, Error> {
             let (subscription_tx, subscription_rx) = unbounded();
-            let (response_tx, mut response_rx) = unbounded();
+            let (response_tx, response_rx) = unbounded();
             // By default we use UUIDs to differentiate subscriptions
             let id = uuid_str();
             self.send_cmd(DriverCommand::Subscribe(SubscribeCommand {
@@ -560,11 +708,11 @@ mod sealed {
                 subscription_tx,
                 response_tx,
             }))?;
-            // Make sure our subscription request went through successfully.
-            response_rx.recv().await.ok_or_else(|| {
-                Error::client_internal("failed to hear back from WebSocket driver".to_string())
-            })??;
-            Ok(Subscription::new(id, query, subscription_rx))
+            Ok(PendingSubscription {
+                id,
+                subscription_rx,
+                response_rx,
+            })
         }
 
         pub async fn unsubscribe(&self, query: Query) -> Result<(), Error> {
@@ -578,6 +726,14 @@ mod sealed {
             })??;
             Ok(())
         }
+
+        pub async fn active_subscriptions(&self) -> Result<Vec<String>, Error> {
+            let (response_tx, mut response_rx) = unbounded();
+            self.send_cmd(DriverCommand::ActiveSubscriptions(response_tx))?;
+            response_rx.recv().await.ok_or_else(|| {
+                Error::client_internal("failed to hear back from WebSocket driver".to_string())
+            })
+        }
     }
 
     /// Allows us to erase the type signatures associated with the different
@@ -593,9 +749,10 @@ mod sealed {
             url: Url,
             compat: CompatMode,
             config: Option<WebSocketConfig>,
+            headers: HeaderMap,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
             let (client, driver) =
-                AsyncTungsteniteClient::<Unsecure>::new(url, compat, config).await?;
+                AsyncTungsteniteClient::<Unsecure>::new(url, compat, config, headers).await?;
             Ok((Self::Unsecure(client), driver))
         }
 
@@ -603,9 +760,10 @@ mod sealed {
             url: Url,
             compat: CompatMode,
             config: Option<WebSocketConfig>,
+            headers: HeaderMap,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
             let (client, driver) =
-                AsyncTungsteniteClient::<Secure>::new(url, compat, config).await?;
+                AsyncTungsteniteClient::<Secure>::new(url, compat, config, headers).await?;
             Ok((Self::Secure(client), driver))
         }
 
@@ -636,16 +794,52 @@ mod sealed {
             }
         }
 
+        pub async fn subscribe_timeout(
+            &self,
+            query: Query,
+            timeout: Duration,
+        ) -> Result<Subscription, Error> {
+            match self {
+                WebSocketClient::Unsecure(c) => c.subscribe_timeout(query, timeout).await,
+                WebSocketClient::Secure(c) => c.subscribe_timeout(query, timeout).await,
+            }
+        }
+
         pub async fn unsubscribe(&self, query: Query) -> Result<(), Error> {
             match self {
                 WebSocketClient::Unsecure(c) => c.unsubscribe(query).await,
                 WebSocketClient::Secure(c) => c.unsubscribe(query).await,
             }
         }
+
+        pub async fn active_subscriptions(&self) -> Result<Vec<String>, Error> {
+            match self {
+                WebSocketClient::Unsecure(c) => c.active_subscriptions().await,
+                WebSocketClient::Secure(c) => c.active_subscriptions().await,
+            }
+        }
     }
 
     use async_tungstenite::tungstenite;
 
+    /// Validate the custom headers supplied to the client builder.
+    pub fn header_map(headers: Vec<(String, String)>) -> Result<HeaderMap, Error> {
+        let headers = crate::client::transport::parse_headers::<HeaderName, HeaderValue>(&headers)?;
+        Ok(headers.into_iter().collect())
+    }
+
+    /// Build the WebSocket upgrade request for the given URL, including any
+    /// custom headers, which replace the ones derived from the URL, see
+    /// `parse_headers`.
+    fn client_request(
+        url: Url,
+        headers: HeaderMap,
+    ) -> Result<tungstenite::handshake::client::Request, Error> {
+        let mut request = url.into_client_request().map_err(Error::tungstenite)?;
+        request.headers_mut().extend(headers);
+        Ok(request)
+    }
+
     impl IntoClientRequest for Url {
         fn into_client_request(
             self,
@@ -681,8 +875,13 @@ mod sealed {
 enum DriverCommand {
     // Initiate a subscription request.
     Subscribe(SubscribeCommand),
+    // Give up on a subscription request which has not yet been confirmed.
+    AbandonSubscribe(SubscriptionId),
     // Initiate an unsubscribe request.
     Unsubscribe(UnsubscribeCommand),
+    // List the queries of the subscriptions currently registered with the
+    // router.
+    ActiveSubscriptions(ChannelTx<Vec<String>>),
     // For non-subscription-related requests.
     SimpleRequest(SimpleRequestCommand),
     Terminate,
@@ -741,6 +940,12 @@ pub struct WebSocketClientDriver {
     pending_commands: HashMap<SubscriptionId, DriverCommand>,
     // The compatibility mode directing how to parse subscription events.
     compat: CompatMode,
+    // How frequently to send pings to the remote endpoint.
+    ping_interval: Duration,
+    // How long to wait for a pong after sending a ping.
+    pong_timeout: Duration,
+    // Whether we have sent a ping for which we haven't received a pong yet.
+    awaiting_pong: bool,
 }
 
 impl WebSocketClientDriver {
@@ -755,9 +960,18 @@ impl WebSocketClientDriver {
             cmd_rx,
             pending_commands: HashMap::new(),
             compat,
+            ping_interval: PING_INTERVAL,
+            pong_timeout: PONG_TIMEOUT,
+            awaiting_pong: false,
         }
     }
 
+    fn keep_alive(mut self, ping_interval: Duration, pong_timeout: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self.pong_timeout = pong_timeout;
+        self
+    }
+
     async fn send_msg(&mut self, msg: Message) -> Result<(), Error> {
         self.stream.send(msg).await.map_err(|e| {
             Error::web_socket("failed to write to WebSocket connection".to_string(), e)
@@ -781,11 +995,14 @@ impl WebSocketClientDriver {
     /// transport.
     pub async fn run(mut self) -> Result<(), Error> {
         let mut ping_interval =
-            tokio::time::interval_at(Instant::now().add(PING_INTERVAL), PING_INTERVAL);
+            tokio::time::interval_at(Instant::now().add(self.ping_interval), self.ping_interval);
 
         let recv_timeout = tokio::time::sleep(RECV_TIMEOUT);
         tokio::pin!(recv_timeout);
 
+        let pong_timeout = tokio::time::sleep(self.pong_timeout);
+        tokio::pin!(pong_timeout);
+
         loop {
             tokio::select! {
                 Some(res) = self.stream.next() => match res {
@@ -795,6 +1012,9 @@ impl WebSocketClientDriver {
                         recv_timeout.as_mut().reset(Instant::now().add(RECV_TIMEOUT));
                         self.handle_incoming_msg(msg).await?
                     },
+                    Err(TungsteniteError::Capacity(CapacityError::MessageTooLong { size, max_size })) => {
+                        return Err(Error::response_too_large(size, max_size));
+                    },
                     Err(e) => return Err(
                         Error::web_socket(
                             "failed to read from WebSocket connection".to_string(),
@@ -804,14 +1024,28 @@ impl WebSocketClientDriver {
                 },
                 Some(cmd) = self.cmd_rx.recv() => match cmd {
                     DriverCommand::Subscribe(subs_cmd) => self.subscribe(subs_cmd).await?,
+                    DriverCommand::AbandonSubscribe(id) => self.abandon_subscribe(id).await,
                     DriverCommand::Unsubscribe(unsubs_cmd) => self.unsubscribe(unsubs_cmd).await?,
+                    DriverCommand::ActiveSubscriptions(response_tx) => {
+                        response_tx.send(self.router.queries())?
+                    },
                     DriverCommand::SimpleRequest(req_cmd) => self.simple_request(req_cmd).await?,
                     DriverCommand::Terminate => return self.close().await,
                 },
-                _ = ping_interval.tick() => self.ping().await?,
+                _ = ping_interval.tick() => {
+                    self.ping().await?;
+                    // Only the oldest unanswered ping counts towards the timeout.
+                    if !self.awaiting_pong {
+                        self.awaiting_pong = true;
+                        pong_timeout.as_mut().reset(Instant::now().add(self.pong_timeout));
+                    }
+                },
                 _ = &mut recv_timeout => {
                     return Err(Error::web_socket_timeout(RECV_TIMEOUT));
                 }
+                _ = &mut pong_timeout, if self.awaiting_pong => {
+                    return Err(Error::web_socket_pong_timeout(self.pong_timeout));
+                }
             }
         }
     }
@@ -851,6 +1085,41 @@ impl WebSocketClientDriver {
         Ok(())
     }
 
+    async fn abandon_subscribe(&mut self, id: SubscriptionId) {
+        let query = match self.pending_commands.remove(&id) {
+            Some(DriverCommand::Subscribe(cmd)) => cmd.query,
+            Some(cmd) => {
+                self.pending_commands.insert(id, cmd);
+                return;
+            },
+            // The subscription has already been confirmed.
+            None => return,
+        };
+
+        // The remote endpoint may still confirm the subscription. Unless
+        // someone else is (about to be) subscribed to the same query, we
+        // issue a fire-and-forget unsubscribe message.
+        let still_wanted = self.router.num_subscriptions_for_query(query.clone()) > 0
+            || self
+                .pending_commands
+                .values()
+                .any(|cmd| matches!(cmd, DriverCommand::Subscribe(other) if other.query == query));
+        if still_wanted {
+            return;
+        }
+
+        debug!(
+            "Abandoned subscription request for query \"{}\". Unsubscribing from query...",
+            query
+        );
+        if let Err(e) = self
+            .send_request(Wrapper::new(unsubscribe::Request::new(query)))
+            .await
+        {
+            error!("Failed to send unsubscribe request: {}", e);
+        }
+    }
+
     async fn unsubscribe(&mut self, cmd: UnsubscribeCommand) -> Result<(), Error> {
         // Terminate all subscriptions for this query immediately. This
         // prioritizes acknowledgement of the caller's wishes over networking
@@ -879,6 +1148,10 @@ impl WebSocketClientDriver {
         match msg {
             Message::Text(s) => self.handle_text_msg(s).await,
             Message::Ping(v) => self.pong(v).await,
+            Message::Pong(_) => {
+                self.awaiting_pong = false;
+                Ok(())
+            },
             _ => Ok(()),
         }
     }
@@ -1012,8 +1285,14 @@ mod test {
     use std::{path::PathBuf, println};
 
     use async_tungstenite::{
-        tokio::{accept_async, TokioAdapter},
-        tungstenite::client::IntoClientRequest,
+        tokio::{accept_async, accept_hdr_async, TokioAdapter},
+        tungstenite::{
+            client::IntoClientRequest,
+            handshake::server::{
+                Callback, ErrorResponse as HandshakeErrorResponse, Request as HandshakeRequest,
+                Response as HandshakeResponse,
+            },
+        },
     };
     use http::{header::AUTHORIZATION, Uri};
     use tokio::{
@@ -1565,4 +1844,319 @@ mod test {
 
         assert_eq!(authorization(&req), None);
     }
+
+    /// Records every `Authorization` header of the upgrade request.
+    struct RecordAuthorization<'a>(&'a mut Vec<String>);
+
+    impl Callback for RecordAuthorization<'_> {
+        fn on_request(
+            self,
+            req: &HandshakeRequest,
+            resp: HandshakeResponse,
+        ) -> Result<HandshakeResponse, HandshakeErrorResponse> {
+            self.0.extend(
+                req.headers()
+                    .get_all(AUTHORIZATION)
+                    .iter()
+                    .map(|h| h.to_str().unwrap().to_owned()),
+            );
+            Ok(resp)
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_header_sent_on_upgrade() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let _ws = accept_hdr_async(stream, RecordAuthorization(&mut received))
+                .await
+                .unwrap();
+            received
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (client, driver) = WebSocketClient::builder(url)
+            .with_header("Authorization", "Bearer some-token")
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(server.await.unwrap(), ["Bearer some-token"]);
+
+        drop(client);
+        drop(driver);
+    }
+
+    #[tokio::test]
+    async fn custom_authorization_replaces_basic_auth() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let _ws = accept_hdr_async(stream, RecordAuthorization(&mut received))
+                .await
+                .unwrap();
+            received
+        });
+
+        let url: WebSocketClientUrl = format!("ws://toto:tata@{addr}/websocket").parse().unwrap();
+        let (client, driver) = WebSocketClient::builder(url)
+            .with_header("Authorization", "Bearer some-token")
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(server.await.unwrap(), ["Bearer some-token"]);
+
+        drop(client);
+        drop(driver);
+    }
+
+    #[tokio::test]
+    async fn subscribe_timeout_abandons_unconfirmed_subscription() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // A server which never confirms subscriptions and reports the method
+        // and query of the requests it receives.
+        let (method_tx, mut method_rx) = unbounded();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            while let Some(Ok(Message::Text(msg))) = ws.next().await {
+                let req: serde_json::Value = serde_json::from_str(&msg).unwrap();
+                let method = req["method"].as_str().unwrap().to_string();
+                let query = req["params"]["query"].as_str().unwrap().to_string();
+                method_tx.send((method, query)).unwrap();
+            }
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (client, driver) = WebSocketClient::builder(url).build().await.unwrap();
+        let driver_handle = tokio::spawn(async move { driver.run().await });
+
+        let timeout = Duration::from_millis(100);
+        let err = client
+            .subscribe_timeout(EventType::NewBlock.into(), timeout)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err.detail(), crate::error::ErrorDetail::Timeout(e) if e.duration == timeout),
+            "{err}"
+        );
+
+        let query = Query::from(EventType::NewBlock).to_string();
+        assert_eq!(
+            method_rx.recv().await.unwrap(),
+            ("subscribe".to_string(), query.clone())
+        );
+        assert_eq!(
+            method_rx.recv().await.unwrap(),
+            ("unsubscribe".to_string(), query)
+        );
+
+        // No subscription to the query is left in the router, and the driver
+        // is still healthy.
+        assert!(client.active_subscriptions().await.unwrap().is_empty());
+        client.close().unwrap();
+        driver_handle.await.unwrap().unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_removes_subscription() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // A server which acknowledges every request and reports the method
+        // and query of the requests it receives.
+        let (method_tx, mut method_rx) = unbounded();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            while let Some(Ok(Message::Text(msg))) = ws.next().await {
+                let req: serde_json::Value = serde_json::from_str(&msg).unwrap();
+                let method = req["method"].as_str().unwrap().to_string();
+                let query = req["params"]["query"].as_str().unwrap().to_string();
+                method_tx.send((method, query)).unwrap();
+
+                let response = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": req["id"],
+                    "result": {},
+                });
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+            }
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (client, driver) = WebSocketClient::builder(url).build().await.unwrap();
+        let driver_handle = tokio::spawn(async move { driver.run().await });
+
+        let query: Query = EventType::NewBlock.into();
+        let mut subs = client.subscribe(query.clone()).await.unwrap();
+        client.unsubscribe(query.clone()).await.unwrap();
+
+        // The subscription's channel is closed once unsubscribed
+        assert!(subs.next().await.is_none());
+
+        // Since the driver no longer tracks any subscription for the query,
+        // subscribing to it again requires a new subscription request.
+        let _subs = client.subscribe(query.clone()).await.unwrap();
+
+        let query = query.to_string();
+        for method in ["subscribe", "unsubscribe", "subscribe"] {
+            assert_eq!(
+                method_rx.recv().await.unwrap(),
+                (method.to_string(), query.clone())
+            );
+        }
+
+        client.close().unwrap();
+        driver_handle.await.unwrap().unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn driver_terminates_when_pongs_stop() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // A server which completes the WebSocket handshake, but then never
+        // reads from the connection, and thus never answers pings.
+        let (hold_tx, mut hold_rx) = unbounded::<()>();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ws = accept_async(stream).await.unwrap();
+            hold_rx.recv().await;
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (_client, driver) = WebSocketClient::builder(url)
+            .ping_interval(Duration::from_millis(50))
+            .pong_timeout(Duration::from_millis(200))
+            .build()
+            .await
+            .unwrap();
+
+        // Only the keep-alive timers are driven by the paused clock
+        tokio::time::pause();
+        let err = tokio::time::timeout(Duration::from_secs(5), driver.run())
+            .await
+            .expect("driver did not terminate")
+            .unwrap_err();
+        assert!(
+            matches!(
+                err.detail(),
+                crate::error::ErrorDetail::WebSocketPongTimeout(e)
+                    if e.timeout == Duration::from_millis(200)
+            ),
+            "{err}"
+        );
+
+        hold_tx.send(()).unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn driver_rejects_oversized_messages() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // A server which sends a single message, far larger than the limit,
+        // and which is not even valid JSON.
+        let (hold_tx, mut hold_rx) = unbounded::<()>();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            ws.send(Message::Text("x".repeat(1024))).await.unwrap();
+            hold_rx.recv().await;
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (_client, driver) = WebSocketClient::builder(url)
+            .max_response_bytes(100)
+            .build()
+            .await
+            .unwrap();
+
+        let err = tokio::time::timeout(Duration::from_secs(5), driver.run())
+            .await
+            .expect("driver did not terminate")
+            .unwrap_err();
+        assert!(
+            matches!(
+                err.detail(),
+                crate::error::ErrorDetail::ResponseTooLarge(e) if e.limit == 100
+            ),
+            "{err}"
+        );
+
+        hold_tx.send(()).unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn driver_keeps_running_while_pongs_arrive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            while let Some(Ok(msg)) = ws.next().await {
+                if let Message::Ping(v) = msg {
+                    ws.send(Message::Pong(v)).await.unwrap();
+                }
+            }
+        });
+
+        let url: WebSocketClientUrl = format!("ws://{addr}/websocket").parse().unwrap();
+        let (client, driver) = WebSocketClient::builder(url)
+            .ping_interval(Duration::from_millis(50))
+            .pong_timeout(Duration::from_millis(200))
+            .build()
+            .await
+            .unwrap();
+        tokio::time::pause();
+        let driver_handle = tokio::spawn(async move { driver.run().await });
+
+        // Several pong timeouts elapse without the connection being dropped.
+        // With the clock paused, time only advances once the pings and pongs
+        // in flight have been handled.
+        for _ in 0..5 {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        assert!(!driver_handle.is_finished());
+
+        client.close().unwrap();
+        driver_handle.await.unwrap().unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn zero_ping_interval_rejected() {
+        let url: WebSocketClientUrl = "ws://127.0.0.1:26657/websocket".parse().unwrap();
+        let result = WebSocketClient::builder(url)
+            .ping_interval(Duration::ZERO)
+            .build()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn invalid_custom_header_rejected() {
+        let url: WebSocketClientUrl = "ws://127.0.0.1:26657/websocket".parse().unwrap();
+        let result = WebSocketClient::builder(url)
+            .with_header("Invalid Header", "value")
+            .build()
+            .await;
+        assert!(result.is_err());
+    }
 }
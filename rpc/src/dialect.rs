@@ -30,3 +30,67 @@ mod sealed {
     impl Sealed for super::v0_34::Dialect {}
     impl Sealed for super::v0_37::Dialect {}
 }
+
+#[cfg(test)]
+mod tests {
+    use tendermint::abci;
+
+    use super::{v0_34, v0_37};
+    use crate::prelude::*;
+
+    fn deliver_tx_events<E>(fixture: &str) -> Vec<abci::Event>
+    where
+        E: serde::de::DeserializeOwned + Into<abci::Event>,
+    {
+        let response: serde_json::Value = serde_json::from_str(fixture).unwrap();
+        let events: Vec<E> =
+            serde_json::from_value(response["result"]["deliver_tx"]["events"].clone()).unwrap();
+        events.into_iter().map(Into::into).collect()
+    }
+
+    fn assert_app_event(event: &abci::Event) {
+        assert_eq!(event.kind, "app");
+
+        let attr = event
+            .attributes
+            .iter()
+            .find(|attr| attr.key_bytes() == b"key")
+            .unwrap();
+        assert_eq!(attr.value_str().unwrap(), "commit-key");
+        assert!(attr.index());
+
+        let attr = event
+            .attributes
+            .iter()
+            .find(|attr| attr.key_str().unwrap() == "noindex_key")
+            .unwrap();
+        assert_eq!(attr.value_str().unwrap(), "index is working");
+        assert!(!attr.index());
+    }
+
+    #[test]
+    fn decode_base64_event_attributes() {
+        let events = deliver_tx_events::<v0_34::Event>(include_str!(
+            "../tests/kvstore_fixtures/v0_34/incoming/broadcast_tx_commit.json"
+        ));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].attributes[0],
+            abci::EventAttribute::V034(_)
+        ));
+        assert_app_event(&events[0]);
+    }
+
+    #[test]
+    fn decode_plain_event_attributes() {
+        let events = deliver_tx_events::<v0_37::Event>(include_str!(
+            "../tests/kvstore_fixtures/v0_37/incoming/broadcast_tx_commit.json"
+        ));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0].attributes[0],
+            abci::EventAttribute::V037(_)
+        ));
+        assert_app_event(&events[0]);
+    }
+}
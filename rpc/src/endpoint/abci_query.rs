@@ -101,3 +101,65 @@ pub struct AbciQuery {
     #[serde(default = "String::new")]
     pub codespace: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Response as _;
+
+    // Response of a Cosmos SDK node to a proven query of an account
+    const RESPONSE_WITH_PROOF: &str = r#"{
+        "jsonrpc": "2.0",
+        "id": "c1a4f7e2-9f43-4e25-b2a5-2f3d2b4ee1a0",
+        "result": {
+            "response": {
+                "code": 0,
+                "log": "",
+                "info": "",
+                "index": "0",
+                "key": "AXRlc3Q=",
+                "value": "dmFsdWU=",
+                "proofOps": {
+                    "ops": [
+                        {
+                            "type": "ics23:iavl",
+                            "key": "AXRlc3Q=",
+                            "data": "CiAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHw=="
+                        },
+                        {
+                            "type": "ics23:simple",
+                            "key": "YWNj",
+                            "data": "EiAgISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0+Pw=="
+                        }
+                    ]
+                },
+                "height": "1234",
+                "codespace": ""
+            }
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_response_with_proof() {
+        let query = Response::from_string(RESPONSE_WITH_PROOF).unwrap().response;
+
+        assert!(query.code.is_ok());
+        assert_eq!(query.key, b"\x01test");
+        assert_eq!(query.value, b"value");
+        assert_eq!(query.height.value(), 1234);
+
+        let ops = query.proof.as_ref().unwrap().ops.as_slice();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0].field_type, "ics23:iavl");
+        assert_eq!(ops[0].key, b"\x01test");
+        assert_eq!(ops[0].data[..2], [0x0a, 0x20]);
+        assert_eq!(ops[1].field_type, "ics23:simple");
+        assert_eq!(ops[1].key, b"acc");
+        assert_eq!(ops[1].data.len(), 34);
+
+        // The proof survives a round trip through the serialized form
+        let serialized = serde_json::to_string(&query).unwrap();
+        let deserialized: AbciQuery = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, query);
+    }
+}
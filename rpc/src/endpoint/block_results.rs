@@ -86,7 +86,7 @@ pub struct Response {
     /// default value when converting responses from nodes using earlier
     /// versions of the protocol.
     #[serde(default)]
-    #[serde(with = "serializers::apphash")]
+    #[serde(with = "serializers::apphash_hex")]
     pub app_hash: AppHash,
 }
 
@@ -46,7 +46,7 @@ pub struct SyncInfo {
     pub earliest_block_hash: Hash,
 
     /// Earliest app hash
-    #[serde(with = "tendermint::serializers::apphash")]
+    #[serde(with = "tendermint::serializers::apphash_hex")]
     pub earliest_app_hash: AppHash,
 
     /// Earliest block height
@@ -60,7 +60,7 @@ pub struct SyncInfo {
     pub latest_block_hash: Hash,
 
     /// Latest app hash
-    #[serde(with = "tendermint::serializers::apphash")]
+    #[serde(with = "tendermint::serializers::apphash_hex")]
     pub latest_app_hash: AppHash,
 
     /// Latest block height
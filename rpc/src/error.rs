@@ -75,6 +75,15 @@ define_error! {
                     e.timeout.as_secs())
             },
 
+        WebSocketPongTimeout
+            {
+                timeout: Duration
+            }
+            | e | {
+                format_args!("no pong received from WebSocket connection within {}ms of a ping",
+                    e.timeout.as_millis())
+            },
+
         MethodNotFound
             {
                 method: String
@@ -91,6 +100,16 @@ define_error! {
                 format_args!("HTTP request failed with non-200 status code: {}", e.status)
             },
 
+        ResponseTooLarge
+            {
+                size: usize,
+                limit: usize,
+            }
+            | e | {
+                format_args!("response of {} bytes exceeds the limit of {} bytes",
+                    e.size, e.limit)
+            },
+
         Parse
             {
                 reason: String
@@ -121,7 +140,7 @@ define_error! {
             }
             | e | {
                 format_args!(
-                    "timed out waiting for healthy response after {}ms",
+                    "timed out after {}ms",
                     e.duration.as_millis()
                 )
             },
@@ -244,3 +263,28 @@ impl Error {
         Error::channel_send()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    fn assert_std_error<E: std::error::Error + Send + Sync + 'static>() {}
+
+    #[test]
+    fn error_is_send_sync_std_error() {
+        assert_std_error::<Error>();
+    }
+
+    #[test]
+    fn io_error_is_reported_as_source() {
+        let err = Error::io(std::io::Error::other("boom"));
+        assert!(matches!(err.detail(), ErrorDetail::Io(_)));
+
+        let sources: Vec<_> = std::iter::successors(err.source(), |&e| e.source())
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(sources, ["I/O error", "boom"]);
+    }
+}
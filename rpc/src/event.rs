@@ -25,7 +25,9 @@ impl Event {
     /// Returns `None` if we don't yet support this event type.
     pub fn event_type(&self) -> Option<EventType> {
         match self.data {
-            EventData::NewBlock { .. } => Some(EventType::NewBlock),
+            EventData::NewBlock { .. } | EventData::LegacyNewBlock { .. } => {
+                Some(EventType::NewBlock)
+            },
             EventData::Tx { .. } => Some(EventType::Tx),
             _ => None,
         }
@@ -56,6 +58,58 @@ pub enum EventData {
     GenericJsonEvent(serde_json::Value),
 }
 
+impl EventData {
+    /// Returns the newly committed block carried by this event, if any.
+    ///
+    /// Both the current and the legacy new block events are supported.
+    pub fn as_new_block(&self) -> Option<&Block> {
+        match self {
+            EventData::NewBlock { block, .. } | EventData::LegacyNewBlock { block, .. } => {
+                block.as_deref()
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the transaction result info carried by this event, if any.
+    pub fn as_tx(&self) -> Option<&TxInfo> {
+        match self {
+            EventData::Tx { tx_result } => Some(tx_result),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the `type` field of some event data is one modelled by
+/// [`EventData`], i.e. an [`EventType`], optionally with the
+/// `tendermint/event/` prefix, or a generic JSON event.
+fn is_known_event_type(ty: &str) -> bool {
+    let event_type = ty.strip_prefix("tendermint/event/").unwrap_or(ty);
+    ty == "GenericJsonEvent" || EventType::ALL.iter().any(|t| t.as_str() == event_type)
+}
+
+/// Deserializes the data of an event, falling back to `generic` with the raw
+/// JSON for event types which are not modelled, instead of failing.
+fn deserialize_event_data<'de, D, T>(
+    deserializer: D,
+    generic: fn(serde_json::Value) -> T,
+) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    use serde::{de::Error as _, Deserialize};
+
+    let value = serde_json::Value::deserialize(deserializer)?;
+    let known = value["type"].as_str().is_some_and(is_known_event_type);
+
+    if known {
+        serde_json::from_value(value).map_err(D::Error::custom)
+    } else {
+        Ok(generic(value))
+    }
+}
+
 /// Transaction result info.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TxInfo {
@@ -89,6 +143,7 @@ pub mod v0_34 {
         /// The query that produced the event.
         pub query: String,
         /// The data associated with the event.
+        #[serde(deserialize_with = "deserialize_data")]
         pub data: DialectEventData,
         /// Event type and attributes map.
         pub events: Option<HashMap<String, Vec<String>>>,
@@ -137,6 +192,13 @@ pub mod v0_34 {
         GenericJsonEvent(serde_json::Value),
     }
 
+    fn deserialize_data<'de, D>(deserializer: D) -> Result<DialectEventData, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize_event_data(deserializer, DialectEventData::GenericJsonEvent)
+    }
+
     impl From<DialectEventData> for EventData {
         fn from(msg: DialectEventData) -> Self {
             match msg {
@@ -266,6 +328,7 @@ mod latest {
         /// The query that produced the event.
         pub query: String,
         /// The data associated with the event.
+        #[serde(deserialize_with = "deserialize_data")]
         pub data: DeEventData,
         /// Event type and attributes map.
         pub events: Option<HashMap<String, Vec<String>>>,
@@ -308,6 +371,13 @@ mod latest {
         GenericJsonEvent(serde_json::Value),
     }
 
+    fn deserialize_data<'de, D>(deserializer: D) -> Result<DeEventData, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize_event_data(deserializer, DeEventData::GenericJsonEvent)
+    }
+
     impl From<DeEventData> for EventData {
         fn from(msg: DeEventData) -> Self {
             match msg {
@@ -561,3 +631,112 @@ pub mod v0_38 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Response;
+
+    fn new_block_event() -> EventData {
+        v0_38::DeEvent::from_string(include_str!(
+            "../tests/kvstore_fixtures/v0_38/incoming/subscribe_newblock_0.json"
+        ))
+        .unwrap()
+        .data
+        .into()
+    }
+
+    fn legacy_new_block_event() -> EventData {
+        v0_37::DeEvent::from_string(include_str!(
+            "../tests/kvstore_fixtures/v0_37/incoming/subscribe_newblock_0.json"
+        ))
+        .unwrap()
+        .data
+        .into()
+    }
+
+    fn tx_event() -> EventData {
+        v0_38::DeEvent::from_string(include_str!(
+            "../tests/kvstore_fixtures/v0_38/incoming/subscribe_txs_0.json"
+        ))
+        .unwrap()
+        .data
+        .into()
+    }
+
+    #[test]
+    fn new_block_accessors() {
+        let data = new_block_event();
+        assert!(matches!(data, EventData::NewBlock { .. }));
+        assert!(data.as_new_block().is_some());
+        assert!(data.as_tx().is_none());
+    }
+
+    #[test]
+    fn legacy_new_block_accessors() {
+        let data = legacy_new_block_event();
+        assert!(matches!(data, EventData::LegacyNewBlock { .. }));
+        assert!(data.as_new_block().is_some());
+        assert!(data.as_tx().is_none());
+    }
+
+    #[test]
+    fn new_block_without_block() {
+        let data = EventData::NewBlock {
+            block: None,
+            block_id: Default::default(),
+            result_finalize_block: None,
+        };
+        assert!(data.as_new_block().is_none());
+        assert!(data.as_tx().is_none());
+    }
+
+    #[test]
+    fn tx_accessors() {
+        let data = tx_event();
+        let tx_result = data.as_tx().unwrap();
+        assert_eq!(tx_result.height, 243);
+        assert!(data.as_new_block().is_none());
+    }
+
+    const VOTE_EVENT: &str = r#"{
+        "query": "tm.event = 'Vote'",
+        "data": {
+            "type": "tendermint/event/Vote",
+            "value": { "Vote": { "height": "42" } }
+        },
+        "events": null
+    }"#;
+
+    #[test]
+    fn unknown_event_type_is_preserved() {
+        let expected = serde_json::json!({
+            "type": "tendermint/event/Vote",
+            "value": { "Vote": { "height": "42" } }
+        });
+
+        let event: Event = serde_json::from_str::<v0_38::DeEvent>(VOTE_EVENT)
+            .unwrap()
+            .into();
+        assert_eq!(event.data, EventData::GenericJsonEvent(expected.clone()));
+        assert_eq!(event.event_type(), None);
+
+        let event: Event = serde_json::from_str::<v0_34::DeEvent>(VOTE_EVENT)
+            .unwrap()
+            .into();
+        assert_eq!(event.data, EventData::GenericJsonEvent(expected));
+    }
+
+    #[test]
+    fn malformed_known_event_type_is_rejected() {
+        let event = VOTE_EVENT.replace("tendermint/event/Vote", "tendermint/event/Tx");
+        assert!(serde_json::from_str::<v0_38::DeEvent>(&event).is_err());
+    }
+
+    #[test]
+    fn generic_json_event_accessors() {
+        let data = EventData::GenericJsonEvent(serde_json::json!({ "type": "unknown" }));
+        assert!(data.as_new_block().is_none());
+        assert!(data.as_tx().is_none());
+    }
+}
@@ -37,7 +37,7 @@ pub mod client;
 #[cfg(any(feature = "http-client", feature = "websocket-client"))]
 pub use client::{
     Client, MockClient, MockRequestMatcher, MockRequestMethodMatcher, Subscription,
-    SubscriptionClient,
+    SubscriptionClient, TrackedEvent,
 };
 #[cfg(feature = "http-client")]
 pub use client::{HttpClient, HttpClientUrl};
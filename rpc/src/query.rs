@@ -169,6 +169,41 @@ impl Query {
         self.conditions.push(Condition::exists(key.to_string()));
         self
     }
+
+    /// Check that the conditions of this query compare each key against a
+    /// single kind of operand.
+    ///
+    /// All conditions applying to the same key must compare it against the
+    /// same kind of operand, e.g. `tx.height = 100 AND tx.height > 50` is
+    /// valid, whereas `tx.height > 50 AND tx.height CONTAINS '1'` mixes a
+    /// numeric comparison with a sub-string match and is rejected. Dates and
+    /// times count as the same kind of operand, and `EXISTS` conditions are
+    /// compatible with any other operation.
+    ///
+    /// Neither the builder methods nor parsing call this check, which is
+    /// stricter than what the node enforces.
+    pub fn validate(&self) -> Result<(), Error> {
+        for (i, first) in self.conditions.iter().enumerate() {
+            let first_kind = match first.operation.operand_kind() {
+                Some(kind) => kind,
+                None => continue,
+            };
+            for second in self.conditions.iter().skip(i + 1) {
+                if second.key != first.key {
+                    continue;
+                }
+                if let Some(second_kind) = second.operation.operand_kind() {
+                    if second_kind != first_kind {
+                        return Err(Error::invalid_params(format!(
+                            "incompatible conditions on key {}: `{}` and `{}`",
+                            first.key, first, second
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for Query {
@@ -399,24 +434,34 @@ pub enum EventType {
     Tx,
 }
 
-impl fmt::Display for EventType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl EventType {
+    /// All the event types, in declaration order.
+    pub const ALL: &'static [EventType] = &[EventType::NewBlock, EventType::Tx];
+
+    /// The value of the `tm.event` key identifying this event type in queries.
+    pub fn as_str(&self) -> &'static str {
         match self {
-            EventType::NewBlock => write!(f, "NewBlock"),
-            EventType::Tx => write!(f, "Tx"),
+            EventType::NewBlock => "NewBlock",
+            EventType::Tx => "Tx",
         }
     }
 }
 
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl FromStr for EventType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
-        match s {
-            "NewBlock" => Ok(Self::NewBlock),
-            "Tx" => Ok(Self::Tx),
-            invalid => Err(Error::unrecognized_event_type(invalid.to_string())),
-        }
+        Self::ALL
+            .iter()
+            .find(|ty| ty.as_str() == s)
+            .cloned()
+            .ok_or_else(|| Error::unrecognized_event_type(s.to_string()))
     }
 }
 
@@ -508,6 +553,30 @@ pub enum Operation {
     Exists,
 }
 
+impl Operation {
+    // The kind of operand this operation compares the key's value against,
+    // if any. Used to detect conditions that cannot be combined on one key.
+    fn operand_kind(&self) -> Option<OperandKind> {
+        match self {
+            Operation::Eq(op)
+            | Operation::Lt(op)
+            | Operation::Lte(op)
+            | Operation::Gt(op)
+            | Operation::Gte(op) => Some(op.kind()),
+            Operation::Contains(_) => Some(OperandKind::String),
+            Operation::Exists => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OperandKind {
+    String,
+    Number,
+    // Dates and times are compared with one another
+    Time,
+}
+
 /// A typed operand for use in an [`Condition`].
 ///
 /// According to the [Tendermint RPC subscribe docs][tm-subscribe],
@@ -526,6 +595,16 @@ pub enum Operand {
     DateTime(OffsetDateTime),
 }
 
+impl Operand {
+    fn kind(&self) -> OperandKind {
+        match self {
+            Operand::String(_) => OperandKind::String,
+            Operand::Signed(_) | Operand::Unsigned(_) | Operand::Float(_) => OperandKind::Number,
+            Operand::Date(_) | Operand::DateTime(_) => OperandKind::Time,
+        }
+    }
+}
+
 impl fmt::Display for Operand {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -671,6 +750,22 @@ mod test {
         assert_eq!("", query.to_string());
     }
 
+    #[test]
+    fn event_type_strings_round_trip() {
+        for (event_type, s) in [(EventType::NewBlock, "NewBlock"), (EventType::Tx, "Tx")] {
+            assert_eq!(event_type.as_str(), s);
+            assert_eq!(event_type.to_string(), s);
+            assert_eq!(EventType::from_str(s).unwrap(), event_type);
+        }
+        for event_type in EventType::ALL {
+            assert_eq!(
+                &EventType::from_str(event_type.as_str()).unwrap(),
+                event_type
+            );
+        }
+        assert!(EventType::from_str("newblock").is_err());
+    }
+
     #[test]
     fn simple_event_type() {
         let query = Query::from(EventType::NewBlock);
@@ -756,6 +851,84 @@ mod test {
         );
     }
 
+    #[test]
+    fn builder_combinators() {
+        let query = Query::from(EventType::NewBlock)
+            .and_eq("tx.height", 100)
+            .and_gt("tx.height", 50);
+        assert_eq!(
+            "tm.event = 'NewBlock' AND tx.height = 100 AND tx.height > 50",
+            query.to_string()
+        );
+        assert!(query.validate().is_ok());
+
+        let query = Query::from(EventType::Tx)
+            .and_gte("tx.height", 10_u64)
+            .and_lt("tx.height", 20_u64)
+            .and_exists("transfer.sender");
+        assert_eq!(
+            "tm.event = 'Tx' AND tx.height >= 10 AND tx.height < 20 AND transfer.sender EXISTS",
+            query.to_string()
+        );
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn builder_escapes_apostrophes() {
+        let query = Query::from(EventType::Tx).and_eq("message.memo", "it's Bob's");
+        assert_eq!(
+            "tm.event = 'Tx' AND message.memo = 'it\\'s Bob\\'s'",
+            query.to_string()
+        );
+
+        let query = Query::from(EventType::Tx).and_contains("message.memo", "'quoted'");
+        assert_eq!(
+            "tm.event = 'Tx' AND message.memo CONTAINS '\\'quoted\\''",
+            query.to_string()
+        );
+    }
+
+    #[test]
+    fn incompatible_operators_rejected() {
+        let query = Query::from(EventType::Tx)
+            .and_gt("tx.height", 50_u64)
+            .and_contains("tx.height", "5");
+        assert!(query.validate().is_err());
+
+        let query = Query::eq("transfer.sender", "AddrA").and_lt("transfer.sender", 5_u64);
+        assert!(query.validate().is_err());
+
+        // Conditions on different keys never conflict
+        let query = Query::gt("tx.height", 50_u64).and_contains("transfer.sender", "Addr");
+        assert!(query.validate().is_ok());
+
+        // Parsing does not validate
+        let query = Query::from_str("tx.height > 5 AND tx.height CONTAINS '5'").unwrap();
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn mixed_date_and_time_range_is_valid() {
+        let query =
+            Query::from_str("tx.date >= DATE 2022-02-03 AND tx.date < TIME 2022-02-04T00:00:00Z")
+                .unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![
+                Condition::gte("tx.date".to_owned(), Operand::Date(date!(2022 - 2 - 3))),
+                Condition::lt(
+                    "tx.date".to_owned(),
+                    Operand::DateTime(datetime!(2022-2-4 0:00 UTC))
+                ),
+            ]
+        );
+        assert!(query.validate().is_ok());
+
+        let query = Query::gte("tx.date", date!(2022 - 2 - 3))
+            .and_lt("tx.date", datetime!(2022-2-4 0:00 UTC));
+        assert!(query.validate().is_ok());
+    }
+
     #[test]
     fn query_event_type_parsing() {
         // Test the empty query (that matches all possible events)
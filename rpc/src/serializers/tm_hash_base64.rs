@@ -1,10 +1,9 @@
 //! Encoding/decoding Tendermint hashes to/from base64.
 
 use serde::{Deserialize, Deserializer, Serializer};
-use subtle_encoding::base64;
 use tendermint::hash::{Algorithm::Sha256, Hash, SHA256_HASH_SIZE};
 
-use crate::prelude::*;
+use crate::{prelude::*, serializers::base64};
 
 /// Deserialize a base64-encoded string into an tendermint::Hash
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Hash, D::Error>
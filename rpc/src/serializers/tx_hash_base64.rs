@@ -1,9 +1,8 @@
 //! Encoding/decoding ABCI transaction hashes to/from base64.
 
 use serde::{Deserialize, Deserializer, Serializer};
-use subtle_encoding::base64;
 
-use crate::prelude::*;
+use crate::{prelude::*, serializers::base64};
 use tendermint::{hash::Algorithm, Hash};
 
 /// Deserialize a base64-encoded string into an abci::transaction::Hash
@@ -59,6 +59,36 @@ fn incoming_fixtures() {
             "block_results_at_height_4555980" => {
                 let r = endpoint::block_results::v0_34::DialectResponse::from_string(content);
                 assert!(r.is_ok(), "block_results_at_height_4555980: {r:?}");
+
+                let r = endpoint::block_results::Response::from(r.unwrap());
+                assert_eq!(r.height.value(), 4555980);
+                assert_eq!(r.txs_results.unwrap().len(), 4);
+                assert!(r.finalize_block_events.is_empty());
+
+                let begin_block_events = r.begin_block_events.unwrap();
+                assert_eq!(begin_block_events.len(), 62);
+                let event = &begin_block_events[0];
+                assert_eq!(event.kind, "coin_received");
+                assert_eq!(event.attributes[0].key_str().unwrap(), "receiver");
+                assert_eq!(
+                    event.attributes[0].value_str().unwrap(),
+                    "inj1m3h30wlvsf8llruxtpukdvsy0km2kum8zcsu4c"
+                );
+
+                let end_block_events = r.end_block_events.unwrap();
+                assert_eq!(end_block_events.len(), 1);
+                assert_eq!(
+                    end_block_events[0].attributes[0].key_str().unwrap(),
+                    "deposit_updates"
+                );
+
+                assert_eq!(r.validator_updates.len(), 1);
+                assert_eq!(r.validator_updates[0].power.value(), 573929);
+                assert_eq!(
+                    r.validator_updates[0].pub_key.to_bytes(),
+                    subtle_encoding::base64::decode("VqJCr3vjQdffcLIG6RMBl2MgXDFYNY6b3Joaa43gV3o=")
+                        .unwrap()
+                );
             },
             "blockchain_from_1_to_10" => {
                 endpoint::blockchain::Response::from_string(content).unwrap();
@@ -568,7 +568,12 @@ fn incoming_fixtures() {
             },
             "commit_at_height_10" => {
                 let result = endpoint::commit::Response::from_string(content).unwrap();
+                assert!(result.canonical);
                 assert!(!result.signed_header.commit.block_id.hash.is_empty());
+                assert_eq!(
+                    result.signed_header.header.hash(),
+                    result.signed_header.commit.block_id.hash
+                );
                 assert_eq!(result.signed_header.commit.height.value(), 10);
                 assert_eq!(result.signed_header.commit.round.value(), 0);
                 assert_eq!(result.signed_header.commit.signatures.len(), 1);
@@ -562,7 +562,12 @@ fn incoming_fixtures() {
             },
             "commit_at_height_10" => {
                 let result = endpoint::commit::Response::from_string(content).unwrap();
+                assert!(result.canonical);
                 assert!(!result.signed_header.commit.block_id.hash.is_empty());
+                assert_eq!(
+                    result.signed_header.header.hash(),
+                    result.signed_header.commit.block_id.hash
+                );
                 assert_eq!(result.signed_header.commit.height.value(), 10);
                 assert_eq!(result.signed_header.commit.round.value(), 0);
                 assert_eq!(result.signed_header.commit.signatures.len(), 1);
@@ -693,6 +698,12 @@ fn incoming_fixtures() {
                 assert_eq!(result.n_peers, 0);
                 assert!(result.peers.is_empty());
             },
+            "status_catching_up" => {
+                let result = endpoint::status::Response::from_string(content).unwrap();
+                assert!(result.sync_info.catching_up);
+                assert_eq!(result.sync_info.latest_block_height.value(), 232);
+                assert_eq!(result.validator_info.power.value(), 10);
+            },
             "status" => {
                 let result = endpoint::status::Response::from_string(content).unwrap();
                 assert_eq!(
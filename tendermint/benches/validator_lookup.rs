@@ -0,0 +1,54 @@
+//! Compares looking up validators by address through `validator::Set::validator`
+//! to a linear scan over the validators, for every validator in a large set.
+//!
+//! Run with `cargo bench -p tendermint --bench validator_lookup`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use tendermint::{account, validator, vote, PublicKey};
+
+const VALIDATORS: u32 = 1000;
+
+fn validator_lookup(c: &mut Criterion) {
+    let pub_key = PublicKey::from_raw_ed25519(&[1; 32]).unwrap();
+    let validators = (0..VALIDATORS)
+        .map(|i| {
+            let mut address = [0; account::LENGTH];
+            address[..4].copy_from_slice(&i.to_be_bytes());
+            validator::Info {
+                address: account::Id::new(address),
+                pub_key,
+                power: vote::Power::from(i % 100 + 1),
+                name: None,
+                proposer_priority: Default::default(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let addresses = validators.iter().map(|v| v.address).collect::<Vec<_>>();
+    let set = validator::Set::without_proposer(validators);
+
+    let mut group = c.benchmark_group("validator_lookup");
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            let found = addresses
+                .iter()
+                .filter_map(|address| set.validators().iter().find(|v| v.address == *address))
+                .count();
+            assert_eq!(black_box(found), VALIDATORS as usize);
+        })
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| {
+            let found = addresses
+                .iter()
+                .filter_map(|address| set.validator(address))
+                .count();
+            assert_eq!(black_box(found), VALIDATORS as usize);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, validator_lookup);
+criterion_main!(benches);
@@ -141,6 +141,12 @@ mod key_conversions {
             }
         }
     }
+
+    impl From<&PublicKey> for Id {
+        fn from(pub_key: &PublicKey) -> Id {
+            Id::from(*pub_key)
+        }
+    }
 }
 
 /// Decode account ID from hex
@@ -186,7 +192,7 @@ impl Serialize for Id {
 #[cfg(all(test, feature = "rust-crypto"))]
 mod tests {
     use super::*;
-    use crate::public_key::Ed25519;
+    use crate::public_key::{Ed25519, PublicKey};
 
     #[test]
     fn test_ed25519_id() {
@@ -225,4 +231,50 @@ mod tests {
 
         assert_eq!(id_bytes.ct_eq(&id).unwrap_u8(), 1);
     }
+
+    #[test]
+    fn test_id_from_public_key_ref() {
+        let ed25519 = PublicKey::from_raw_ed25519(
+            &hex::decode_upper("14253D61EF42D166D02E68D540D07FDF8D65A9AF0ACAA46302688E788A8521E2")
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            Id::from(&ed25519),
+            Id::from_str("0CDA3F47EF3C4906693B170EF650EB968C5F4B2C").unwrap()
+        );
+
+        #[cfg(feature = "secp256k1")]
+        {
+            let secp256k1 = PublicKey::from_raw_secp256k1(
+                &hex::decode_upper(
+                    "02950E1CDFCB133D6024109FD489F734EEB4502418E538C28481F22BCE276F248C",
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                Id::from(&secp256k1),
+                Id::from_str("7C2BB42A8BE69791EC763E51F5A49BCD41E82237").unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_accepts_either_case() {
+        let upper: Id =
+            serde_json::from_str("\"0CDA3F47EF3C4906693B170EF650EB968C5F4B2C\"").unwrap();
+        let lower: Id =
+            serde_json::from_str("\"0cda3f47ef3c4906693b170ef650eb968c5f4b2c\"").unwrap();
+        assert_eq!(upper, lower);
+
+        // Both serialize and display as upper case hex
+        let expected = "0CDA3F47EF3C4906693B170EF650EB968C5F4B2C";
+        assert_eq!(
+            serde_json::to_string(&lower).unwrap(),
+            format!("\"{expected}\"")
+        );
+        assert_eq!(lower.to_string(), expected);
+        assert_eq!(Id::from_str(&lower.to_string()).unwrap(), upper);
+    }
 }
@@ -1,11 +1,15 @@
 //! Commits to a Tendermint blockchain
 
+use alloc::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 use tendermint_proto::v0_37::types::Commit as RawCommit;
 
 use crate::{
-    block::{commit_sig::CommitSig, Height, Id, Round},
+    block::{commit_sig::CommitSig, Header, Height, Id, Round},
+    error::Error,
     prelude::*,
+    validator,
 };
 
 /// Commit contains the justification (ie. a set of signatures) that a block was committed by a set
@@ -71,6 +75,57 @@ tendermint_pb_modules! {
     }
 }
 
+impl Commit {
+    /// Performs basic validation of this commit against the header it commits
+    /// to and the validator set of that header.
+    ///
+    /// Checks that the commit is for the same height as the header and for
+    /// an actual block rather than a nil block ID, that it holds exactly one
+    /// signature per validator, and that no validator signed more than once,
+    /// which would otherwise inflate the voting power backing the commit.
+    pub fn validate_basic(
+        &self,
+        header: &Header,
+        validators: &validator::Set,
+    ) -> Result<(), Error> {
+        if self.height != header.height {
+            return Err(Error::invalid_commit(format!(
+                "commit height {} does not match header height {}",
+                self.height, header.height
+            )));
+        }
+
+        if self.block_id.is_empty() {
+            return Err(Error::invalid_commit(
+                "commit cannot be for a nil block ID".to_string(),
+            ));
+        }
+
+        if self.signatures.len() != validators.validators().len() {
+            return Err(Error::invalid_commit(format!(
+                "commit has {} signatures, but the validator set has {} validators",
+                self.signatures.len(),
+                validators.validators().len()
+            )));
+        }
+
+        let mut seen = BTreeSet::new();
+        for address in self
+            .signatures
+            .iter()
+            .filter_map(CommitSig::validator_address)
+        {
+            if !seen.insert(address) {
+                return Err(Error::invalid_commit(format!(
+                    "duplicate signature from validator {address}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for Commit {
     fn default() -> Self {
         Commit {
@@ -82,3 +137,113 @@ impl Default for Commit {
         }
     }
 }
+
+#[cfg(all(test, feature = "rust-crypto"))]
+mod tests {
+    use super::*;
+    use crate::{error::ErrorDetail, vote, PublicKey, Time};
+
+    fn header() -> Header {
+        serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/header_with_known_hash.json"
+        ))
+        .unwrap()
+    }
+
+    fn validator(byte: u8) -> validator::Info {
+        let pk = PublicKey::from_raw_ed25519(&[byte; 32]).unwrap();
+        validator::Info::new(pk, vote::Power::from(1_u32))
+    }
+
+    fn commit_sig(validator: &validator::Info) -> CommitSig {
+        CommitSig::BlockIdFlagCommit {
+            validator_address: validator.address,
+            timestamp: Time::unix_epoch(),
+            signature: None,
+        }
+    }
+
+    fn commit(header: &Header, signatures: Vec<CommitSig>) -> Commit {
+        Commit {
+            height: header.height,
+            block_id: Id {
+                hash: header.hash(),
+                part_set_header: Default::default(),
+            },
+            signatures,
+            ..Commit::default()
+        }
+    }
+
+    fn assert_invalid_commit(result: Result<(), Error>) {
+        assert!(matches!(
+            result.unwrap_err().detail(),
+            ErrorDetail::InvalidCommit(_)
+        ));
+    }
+
+    #[test]
+    fn valid_commit() {
+        let header = header();
+        let (a, b) = (validator(1), validator(2));
+        let validators = validator::Set::without_proposer(vec![a.clone(), b]);
+        let commit = commit(&header, vec![commit_sig(&a), CommitSig::BlockIdFlagAbsent]);
+        commit.validate_basic(&header, &validators).unwrap();
+    }
+
+    #[test]
+    fn height_mismatch() {
+        let header = header();
+        let a = validator(1);
+        let validators = validator::Set::without_proposer(vec![a.clone()]);
+        let mut commit = commit(&header, vec![commit_sig(&a)]);
+        commit.height = header.height.increment();
+        assert_invalid_commit(commit.validate_basic(&header, &validators));
+    }
+
+    #[test]
+    fn nil_block_id() {
+        let header = header();
+        let a = validator(1);
+        let validators = validator::Set::without_proposer(vec![a.clone()]);
+        let mut commit = commit(&header, vec![commit_sig(&a)]);
+        commit.block_id = Id::default();
+        assert_invalid_commit(commit.validate_basic(&header, &validators));
+    }
+
+    #[test]
+    fn signature_count_mismatch() {
+        let header = header();
+        let (a, b) = (validator(1), validator(2));
+        let validators = validator::Set::without_proposer(vec![a.clone(), b]);
+        let commit = commit(&header, vec![commit_sig(&a)]);
+        assert_invalid_commit(commit.validate_basic(&header, &validators));
+    }
+
+    #[test]
+    fn duplicate_validator() {
+        let header = header();
+        let (a, b) = (validator(1), validator(2));
+        let validators = validator::Set::without_proposer(vec![a.clone(), b]);
+        let commit = commit(&header, vec![commit_sig(&a), commit_sig(&a)]);
+        assert_invalid_commit(commit.validate_basic(&header, &validators));
+    }
+
+    #[test]
+    fn deserialize_precommits_alias() {
+        let signed_header: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/signed_header.json"
+        ))
+        .unwrap();
+        let with_signatures = signed_header["commit"].clone();
+        let mut with_precommits = with_signatures.clone();
+        let fields = with_precommits.as_object_mut().unwrap();
+        let signatures = fields.remove("signatures").unwrap();
+        fields.insert("precommits".to_string(), signatures);
+
+        let from_signatures: Commit = serde_json::from_value(with_signatures).unwrap();
+        let from_precommits: Commit = serde_json::from_value(with_precommits).unwrap();
+        assert_eq!(from_signatures.signatures.len(), 1);
+        assert_eq!(from_precommits, from_signatures);
+    }
+}
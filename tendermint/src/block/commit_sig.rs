@@ -91,8 +91,7 @@ tendermint_pb_modules! {
 
         fn try_from(value: RawCommitSig) -> Result<Self, Self::Error> {
             if value.block_id_flag == BlockIdFlag::Absent as i32 {
-                if value.timestamp.is_some() {
-                    let timestamp = value.timestamp.unwrap();
+                if let Some(timestamp) = value.timestamp {
                     // 0001-01-01T00:00:00.000Z translates to EPOCH-62135596800 seconds
                     if timestamp.nanos != 0 || timestamp.seconds != -62135596800 {
                         return Err(Error::invalid_timestamp(
@@ -205,4 +204,47 @@ tendermint_pb_modules! {
         let commit_sig = CommitSig::try_from(raw_commit_sg).unwrap();
         assert_eq!(commit_sig, CommitSig::BlockIdFlagAbsent);
     }
+
+    #[test]
+    fn test_block_id_flag_round_trip() {
+        let validator_address = crate::account::Id::new([1; 20]);
+        let timestamp = crate::Time::from_unix_timestamp(1, 0).unwrap();
+        let signature = Signature::new(vec![2; 64]).unwrap();
+        let commit_sigs = [
+            (CommitSig::BlockIdFlagAbsent, BlockIdFlag::Absent),
+            (
+                CommitSig::BlockIdFlagCommit {
+                    validator_address,
+                    timestamp,
+                    signature: signature.clone(),
+                },
+                BlockIdFlag::Commit,
+            ),
+            (
+                CommitSig::BlockIdFlagNil {
+                    validator_address,
+                    timestamp,
+                    signature,
+                },
+                BlockIdFlag::Nil,
+            ),
+        ];
+
+        for (commit_sig, flag) in commit_sigs {
+            let raw = RawCommitSig::from(commit_sig.clone());
+            assert_eq!(raw.block_id_flag, flag as i32);
+            assert_eq!(CommitSig::try_from(raw).unwrap(), commit_sig);
+        }
+    }
+
+    #[test]
+    fn test_block_id_flag_invalid() {
+        let json = r#"{"block_id_flag":7,"validator_address":"","timestamp":"0001-01-01T00:00:00Z","signature":""}"#;
+        let raw_commit_sig = serde_json::from_str::<RawCommitSig>(json).unwrap();
+        let err = CommitSig::try_from(raw_commit_sig).unwrap_err();
+        assert!(
+            matches!(err.detail(), crate::error::ErrorDetail::BlockIdFlag(_)),
+            "{err}"
+        );
+    }
 }
@@ -252,15 +252,71 @@ tendermint_pb_modules! {
 
 #[cfg(test)]
 mod tests {
+    use subtle_encoding::hex;
+    use tendermint_proto::{v0_38::types::Header as RawHeader, Protobuf};
+
     use super::Header;
     use crate::test::test_serialization_roundtrip;
 
+    // The protobuf encoding of the header in `header_with_known_hash.json`,
+    // whose hash, checked in `header_hashing`, matches the one Go computes.
+    const HEADER_WITH_KNOWN_HASH_PROTOBUF: &str = concat!(
+        "0a04080b1001120b646f636b6572636861696e18c80c220b08f2b6fefa0510e29b86652a",
+        "480a20d3b2cc7edaff87433a5dbcdcdf4077a56aacde3606034262b0cdb120f62eb40b12",
+        "24080112203ab411eafe9a3b7ac013b0214990e5653112a39909289e3ea9211f07b8cd6e",
+        "ed322047071b86efc28bec17543967975f35191ba9bec9c2ad77e86f63b149528d71a13a",
+        "20e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85542205e",
+        "20520ec80b84044b64ba0c55b1c06d543bbd57955c27b8a9999ec526bf703c4a205e2052",
+        "0ec80b84044b64ba0c55b1c06d543bbd57955c27b8a9999ec526bf703c5220048091bc7d",
+        "dc283f77bfbf91d73c44da58c3df8a9cbc867405d8b7f3daada22f5a0800000000000000",
+        "006220e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b8556a",
+        "20e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b8557214c8",
+        "657a30d20c3bad414624a1a963373dd500ccd3",
+    );
+
     #[test]
     fn serialization_roundtrip() {
         let json_data = include_str!("../../tests/support/serialization/block/header.json");
         test_serialization_roundtrip::<Header>(json_data);
     }
 
+    #[test]
+    fn protobuf_roundtrip_is_byte_exact() {
+        let bytes = hex::decode(HEADER_WITH_KNOWN_HASH_PROTOBUF).unwrap();
+        let expected: Header = serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/header_with_known_hash.json"
+        ))
+        .unwrap();
+
+        let header = <Header as Protobuf<RawHeader>>::decode_vec(&bytes).unwrap();
+        assert_eq!(header, expected);
+        assert_eq!(Protobuf::<RawHeader>::encode_vec(header), bytes);
+    }
+
+    #[test]
+    fn decoding_truncated_protobuf_does_not_panic() {
+        let bytes = hex::decode(HEADER_WITH_KNOWN_HASH_PROTOBUF).unwrap();
+        for len in 0..bytes.len() {
+            let _ = <Header as Protobuf<RawHeader>>::decode_vec(&bytes[..len]);
+        }
+
+        // Cut in the middle of the chain ID
+        assert!(<Header as Protobuf<RawHeader>>::decode_vec(&bytes[..10]).is_err());
+    }
+
+    #[test]
+    fn decoding_oversized_length_prefix_fails() {
+        // The chain ID field, claiming a length of 2^32 - 1 bytes
+        let bytes = [0x12, 0xff, 0xff, 0xff, 0xff, 0x0f, b'a'];
+        assert!(<Header as Protobuf<RawHeader>>::decode_vec(&bytes).is_err());
+
+        // The last block ID field, claiming a length overflowing a u64
+        let bytes = [
+            0x2a, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+        ];
+        assert!(<Header as Protobuf<RawHeader>>::decode_vec(&bytes).is_err());
+    }
+
     #[cfg(feature = "rust-crypto")]
     mod crypto {
         use super::*;
@@ -71,14 +71,12 @@ tendermint_pb_modules! {
         type Error = Error;
 
         fn try_from(value: RawBlockId) -> Result<Self, Self::Error> {
-            if value.part_set_header.is_none() {
-                return Err(Error::invalid_part_set_header(
-                    "part_set_header is None".to_string(),
-                ));
-            }
+            let part_set_header = value.part_set_header.ok_or_else(|| {
+                Error::invalid_part_set_header("part_set_header is None".to_string())
+            })?;
             Ok(Self {
                 hash: value.hash.try_into()?,
-                part_set_header: value.part_set_header.unwrap().try_into()?,
+                part_set_header: part_set_header.try_into()?,
             })
         }
     }
@@ -110,14 +108,12 @@ tendermint_pb_modules! {
         type Error = Error;
 
         fn try_from(value: RawCanonicalBlockId) -> Result<Self, Self::Error> {
-            if value.part_set_header.is_none() {
-                return Err(Error::invalid_part_set_header(
-                    "part_set_header is None".to_string(),
-                ));
-            }
+            let part_set_header = value.part_set_header.ok_or_else(|| {
+                Error::invalid_part_set_header("part_set_header is None".to_string())
+            })?;
             Ok(Self {
                 hash: value.hash.try_into()?,
-                part_set_header: value.part_set_header.unwrap().try_into()?,
+                part_set_header: part_set_header.try_into()?,
             })
         }
     }
@@ -139,6 +135,23 @@ impl Id {
         result.truncate(PREFIX_LENGTH);
         result
     }
+
+    /// Whether this is a nil block ID, i.e. one with an empty hash, as used
+    /// by votes for no block.
+    pub fn is_empty(&self) -> bool {
+        self.hash.is_empty()
+    }
+
+    /// Compare two block IDs, treating all nil block IDs (see
+    /// [`Id::is_empty`]) as equal regardless of their part set headers.
+    ///
+    /// The part set headers of non-nil block IDs are compared as usual.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        if self.hash != other.hash {
+            return false;
+        }
+        self.is_empty() || self.part_set_header == other.part_set_header
+    }
 }
 
 // TODO: match gaia serialization? e.g `D2F5991B98D708FD2C25AA2BEBED9358F24177DE:1:C37A55FB95E9`
@@ -189,4 +202,43 @@ mod tests {
         let id = Id::from_str(EXAMPLE_SHA256_ID).unwrap();
         assert_eq!(&id.to_string(), EXAMPLE_SHA256_ID)
     }
+
+    #[test]
+    fn nil_ids_are_empty() {
+        assert!(Id::default().is_empty());
+        assert!(!Id::from_str(EXAMPLE_SHA256_ID).unwrap().is_empty());
+        let zero = Id::from_str("0000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap();
+        assert!(!zero.is_empty());
+    }
+
+    #[test]
+    fn semantic_eq_ignores_part_set_header_of_nil_ids() {
+        let nil = Id::default();
+        let noisy_nil = Id {
+            hash: Hash::None,
+            part_set_header: PartSetHeader::new(1, Hash::from_str(EXAMPLE_SHA256_ID).unwrap())
+                .unwrap(),
+        };
+        assert_ne!(nil, noisy_nil);
+        assert!(nil.semantic_eq(&noisy_nil));
+        assert!(noisy_nil.semantic_eq(&nil));
+    }
+
+    #[test]
+    fn semantic_eq_compares_non_nil_ids() {
+        let id = Id::from_str(EXAMPLE_SHA256_ID).unwrap();
+        let other =
+            Id::from_str("0000000000000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        assert!(id.semantic_eq(&id));
+        assert!(!id.semantic_eq(&other));
+        assert!(!id.semantic_eq(&Id::default()));
+
+        let with_parts = Id {
+            part_set_header: PartSetHeader::new(1, id.hash).unwrap(),
+            ..id
+        };
+        assert!(!id.semantic_eq(&with_parts));
+    }
 }
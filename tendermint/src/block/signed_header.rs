@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 use tendermint_proto::v0_37::types::SignedHeader as RawSignedHeader;
 
-use crate::{block, Error};
+use crate::{block, crypto::Sha256, merkle::MerkleHash, prelude::*, Error, Hash};
 
 /// Signed block headers
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -18,6 +18,19 @@ pub struct SignedHeader {
     pub commit: block::Commit,
 }
 
+/// Outcome of comparing two signed headers, e.g. obtained from different
+/// peers, with [`SignedHeader::compare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderComparison {
+    /// Both headers are at the same height and hash identically.
+    Identical,
+    /// Both headers are at the same height, but their hashes differ, which
+    /// points to a fork if both are signed by a valid commit.
+    SameHeightDifferentHash,
+    /// The headers are at different heights, and cannot be compared.
+    DifferentHeight,
+}
+
 tendermint_pb_modules! {
     use super::SignedHeader;
     use crate::Error;
@@ -69,4 +82,339 @@ impl SignedHeader {
     pub fn commit(&self) -> &block::Commit {
         &self.commit
     }
+
+    /// Computes the hash of the header.
+    ///
+    /// This is the hash the commit signs over, and the one referred to by the
+    /// `last_block_id` of the next header in the chain.
+    #[cfg(feature = "rust-crypto")]
+    pub fn hash(&self) -> Hash {
+        self.header.hash()
+    }
+
+    /// Hash the header with a Merkle hasher provided by a crypto provider.
+    pub fn hash_with<H>(&self) -> Hash
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.header.hash_with::<H>()
+    }
+
+    /// Check that the commit is for this header, i.e. that the hash of the
+    /// block ID signed by the commit is the hash of the header.
+    ///
+    /// This catches mismatched header and commit pairs before verifying the
+    /// commit signatures.
+    #[cfg(feature = "rust-crypto")]
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_with::<crate::crypto::default::Sha256>()
+    }
+
+    /// Check that the commit is for this header, hashing the header with a
+    /// Merkle hasher provided by a crypto provider.
+    ///
+    /// See [`SignedHeader::validate`].
+    pub fn validate_with<H>(&self) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        let header_hash = self.hash_with::<H>();
+        let commit_hash = self.commit.block_id.hash;
+        if header_hash != commit_hash {
+            return Err(Error::commit_header_mismatch(header_hash, commit_hash));
+        }
+        Ok(())
+    }
+
+    /// Compare this signed header to `other`, which is expected to be at the
+    /// same height.
+    ///
+    /// The full header hashes are compared, so that headers differing in any
+    /// field, and not only in their app hash, are told apart.
+    #[cfg(feature = "rust-crypto")]
+    pub fn compare(&self, other: &SignedHeader) -> HeaderComparison {
+        self.compare_with::<crate::crypto::default::Sha256>(other)
+    }
+
+    /// Compare this signed header to `other`, hashing the headers with a
+    /// Merkle hasher provided by a crypto provider.
+    ///
+    /// See [`SignedHeader::compare`].
+    pub fn compare_with<H>(&self, other: &SignedHeader) -> HeaderComparison
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        if self.header.height != other.header.height {
+            HeaderComparison::DifferentHeight
+        } else if self.hash_with::<H>() == other.hash_with::<H>() {
+            HeaderComparison::Identical
+        } else {
+            HeaderComparison::SameHeightDifferentHash
+        }
+    }
+
+    /// Check that this signed header directly follows `prev` in the chain.
+    ///
+    /// This is required for sequential (non-skipping) verification, and checks
+    /// that:
+    /// - the height of this header is exactly one above the height of `prev`;
+    /// - the time of this header is strictly after the time of `prev`;
+    /// - `last_block_id` refers to `prev`, both by header hash and by the
+    ///   part set header `prev` was committed with.
+    #[cfg(feature = "rust-crypto")]
+    pub fn verify_chain_link(&self, prev: &SignedHeader) -> Result<(), Error> {
+        self.verify_chain_link_with::<crate::crypto::default::Sha256>(prev)
+    }
+
+    /// Check that this signed header directly follows `prev` in the chain,
+    /// hashing the previous header with a Merkle hasher provided by a crypto
+    /// provider.
+    ///
+    /// See [`SignedHeader::verify_chain_link`] for the conditions checked.
+    pub fn verify_chain_link_with<H>(&self, prev: &SignedHeader) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.verify_chain_link_with_opts::<H>(prev, false)
+    }
+
+    /// Check that this signed header directly follows `prev` in the chain, as
+    /// [`SignedHeader::verify_chain_link_with`] does.
+    ///
+    /// When `allow_equal_time` is set, the time of this header may also be
+    /// equal to the time of `prev`, as for blocks whose time is set from the
+    /// genesis time.
+    pub fn verify_chain_link_with_opts<H>(
+        &self,
+        prev: &SignedHeader,
+        allow_equal_time: bool,
+    ) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        if self.header.height != prev.header.height.increment() {
+            return Err(Error::invalid_chain_link(format!(
+                "height {} does not follow previous height {}",
+                self.header.height, prev.header.height
+            )));
+        }
+
+        let time_ok = self.header.time.after(prev.header.time)
+            || (allow_equal_time && self.header.time == prev.header.time);
+        if !time_ok {
+            return Err(Error::invalid_chain_link(format!(
+                "time {} is not after previous time {}",
+                self.header.time, prev.header.time
+            )));
+        }
+
+        let last_block_id = self
+            .header
+            .last_block_id
+            .ok_or_else(|| Error::invalid_chain_link("missing last_block_id".to_string()))?;
+
+        let prev_hash = prev.header.hash_with::<H>();
+        if last_block_id.hash != prev_hash {
+            return Err(Error::invalid_chain_link(format!(
+                "last_block_id hash {} does not match previous header hash {}",
+                last_block_id.hash, prev_hash
+            )));
+        }
+
+        if last_block_id.part_set_header != prev.commit.block_id.part_set_header {
+            return Err(Error::invalid_chain_link(
+                "last_block_id part set header does not match previous block".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "rust-crypto"))]
+mod tests {
+    use core::time::Duration;
+
+    use super::{HeaderComparison, SignedHeader};
+    use crate::{
+        account,
+        block::{self, parts, Commit, Header},
+        error::ErrorDetail,
+        hash::{Algorithm, Hash},
+        prelude::*,
+    };
+
+    fn signed_header(header: Header, part_set_header: parts::Header) -> SignedHeader {
+        let commit = Commit {
+            height: header.height,
+            block_id: block::Id {
+                hash: header.hash(),
+                part_set_header,
+            },
+            ..Commit::default()
+        };
+        SignedHeader::new(header, commit).unwrap()
+    }
+
+    fn part_set_header(byte: u8) -> parts::Header {
+        parts::Header::new(1, Hash::from_bytes(Algorithm::Sha256, &[byte; 32]).unwrap()).unwrap()
+    }
+
+    // Returns a pair of signed headers where the second one follows the first.
+    fn chain_link() -> (SignedHeader, Header) {
+        let header: Header = serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/header_with_known_hash.json"
+        ))
+        .unwrap();
+        let prev = signed_header(header.clone(), part_set_header(1));
+
+        let mut next = header;
+        next.height = prev.header.height.increment();
+        next.time = (prev.header.time + Duration::from_secs(1)).unwrap();
+        next.last_block_id = Some(prev.commit.block_id);
+
+        (prev, next)
+    }
+
+    #[test]
+    fn hash_matches_commit_block_id() {
+        // Signed header at height 10 of a CometBFT v0.38 kvstore chain
+        let signed_header: SignedHeader = serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/signed_header.json"
+        ))
+        .unwrap();
+        let expected_hash = Hash::from_hex_upper(
+            Algorithm::Sha256,
+            "00ECDAC463C201ECD4BDBBAAE4A53A4C80291D4051FD69ED97F6420CE1388BFE",
+        )
+        .unwrap();
+
+        assert_eq!(signed_header.hash(), expected_hash);
+        assert_eq!(signed_header.hash(), signed_header.commit.block_id.hash);
+    }
+
+    #[test]
+    fn deserializing_malformed_signature_fails() {
+        let mut signed_header: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/support/serialization/block/signed_header.json"
+        ))
+        .unwrap();
+        // The base64 decoder of `subtle-encoding` panics on misplaced padding
+        for signature in ["=", "AAAA=", "AA=A", "AAAAA=="] {
+            signed_header["commit"]["signatures"][0]["signature"] = signature.into();
+            assert!(serde_json::from_value::<SignedHeader>(signed_header.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_matching_commit() {
+        let (prev, _) = chain_link();
+        prev.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_commit_for_other_block() {
+        let (prev, next) = chain_link();
+        let other = signed_header(next, part_set_header(2));
+        let mismatched = SignedHeader::new(prev.header.clone(), {
+            let mut commit = other.commit;
+            commit.height = prev.header.height;
+            commit
+        })
+        .unwrap();
+
+        match mismatched.validate().unwrap_err().detail() {
+            ErrorDetail::CommitHeaderMismatch(e) => {
+                assert_eq!(e.header_hash, prev.hash());
+                assert_eq!(e.commit_hash, other.header.hash());
+            },
+            e => panic!("expected CommitHeaderMismatch, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn valid_chain_link() {
+        let (prev, next) = chain_link();
+        let next = signed_header(next, part_set_header(2));
+        next.verify_chain_link(&prev).unwrap();
+    }
+
+    #[test]
+    fn broken_chain_link() {
+        let (prev, mut next) = chain_link();
+        next.last_block_id = Some(block::Id {
+            hash: Hash::from_bytes(Algorithm::Sha256, &[0xAB; 32]).unwrap(),
+            part_set_header: prev.commit.block_id.part_set_header,
+        });
+        let next = signed_header(next, part_set_header(2));
+        assert!(next.verify_chain_link(&prev).is_err());
+    }
+
+    #[test]
+    fn mismatched_part_set_header() {
+        let (prev, mut next) = chain_link();
+        next.last_block_id = Some(block::Id {
+            hash: prev.commit.block_id.hash,
+            part_set_header: part_set_header(3),
+        });
+        let next = signed_header(next, part_set_header(2));
+        assert!(next.verify_chain_link(&prev).is_err());
+    }
+
+    #[test]
+    fn non_monotonic_time() {
+        let (prev, mut next) = chain_link();
+        next.time = (prev.header.time - Duration::from_secs(1)).unwrap();
+        let next = signed_header(next, part_set_header(2));
+        assert!(next.verify_chain_link(&prev).is_err());
+    }
+
+    #[test]
+    fn equal_time() {
+        let (prev, mut next) = chain_link();
+        next.time = prev.header.time;
+        let next = signed_header(next, part_set_header(2));
+        assert!(next.verify_chain_link(&prev).is_err());
+        next.verify_chain_link_with_opts::<crate::crypto::default::Sha256>(&prev, true)
+            .unwrap();
+    }
+
+    #[test]
+    fn non_adjacent_height() {
+        let (prev, mut next) = chain_link();
+        next.height = next.height.increment();
+        let next = signed_header(next, part_set_header(2));
+        assert!(next.verify_chain_link(&prev).is_err());
+    }
+
+    #[test]
+    fn compare_identical() {
+        let (prev, _) = chain_link();
+        assert_eq!(prev.compare(&prev.clone()), HeaderComparison::Identical);
+    }
+
+    #[test]
+    fn compare_same_height_different_hash() {
+        let (prev, _) = chain_link();
+        // The app hash is left untouched, only the proposer differs.
+        let mut header = prev.header.clone();
+        header.proposer_address = account::Id::new([0xAB; 20]);
+        let fork = signed_header(header, part_set_header(1));
+        assert_eq!(prev.header.app_hash, fork.header.app_hash);
+        assert_eq!(
+            prev.compare(&fork),
+            HeaderComparison::SameHeightDifferentHash
+        );
+        assert_eq!(
+            fork.compare(&prev),
+            HeaderComparison::SameHeightDifferentHash
+        );
+    }
+
+    #[test]
+    fn compare_different_height() {
+        let (prev, next) = chain_link();
+        let next = signed_header(next, part_set_header(2));
+        assert_eq!(prev.compare(&next), HeaderComparison::DifferentHeight);
+    }
 }
@@ -27,15 +27,24 @@ impl TryFrom<String> for Id {
     type Error = Error;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.is_empty() || value.len() > MAX_LENGTH {
-            return Err(Error::length());
+        if value.is_empty() {
+            return Err(Error::invalid_chain_id("empty".to_string()));
         }
 
-        for byte in value.as_bytes() {
-            match byte {
-                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => (),
-                _ => return Err(Error::parse("chain id charset".to_string())),
-            }
+        if value.len() > MAX_LENGTH {
+            return Err(Error::invalid_chain_id(format!(
+                "length {} exceeds the maximum of {}",
+                value.len(),
+                MAX_LENGTH
+            )));
+        }
+
+        // Only allow printable ASCII, as the chain ID is part of the sign
+        // bytes of votes and proposals.
+        if let Some(byte) = value.bytes().find(|b| !(b' '..=b'~').contains(b)) {
+            return Err(Error::invalid_chain_id(format!(
+                "invalid character 0x{byte:02x}"
+            )));
         }
 
         Ok(Id(value))
@@ -151,11 +160,17 @@ mod tests {
         assert_eq!(&long_id.parse::<Id>().unwrap().as_str(), &long_id);
     }
 
+    #[test]
+    fn parses_cosmoshub_chain_id() {
+        let id = Id::try_from("cosmoshub-4").unwrap();
+        assert_eq!(id.as_str(), "cosmoshub-4");
+    }
+
     #[test]
     fn rejects_empty_chain_ids() {
         match "".parse::<Id>().unwrap_err().detail() {
-            ErrorDetail::Length(_) => {},
-            _ => panic!("expected length error"),
+            ErrorDetail::InvalidChainId(_) => {},
+            _ => panic!("expected invalid chain id error"),
         }
     }
 
@@ -163,8 +178,23 @@ mod tests {
     fn rejects_overlength_chain_ids() {
         let overlong_id = String::from_utf8(vec![b'x'; MAX_LENGTH + 1]).unwrap();
         match overlong_id.parse::<Id>().unwrap_err().detail() {
-            ErrorDetail::Length(_) => {},
-            _ => panic!("expected length error"),
+            ErrorDetail::InvalidChainId(_) => {},
+            _ => panic!("expected invalid chain id error"),
+        }
+    }
+
+    #[test]
+    fn rejects_non_printable_chain_ids() {
+        for id in [
+            "gaia\n9000",
+            "gaia\t9000",
+            "gaia\u{7f}9000",
+            "gaia-9000\u{e9}",
+        ] {
+            match Id::try_from(id).unwrap_err().detail() {
+                ErrorDetail::InvalidChainId(_) => {},
+                _ => panic!("expected invalid chain id error for {id:?}"),
+            }
         }
     }
 }
@@ -154,6 +154,31 @@ define_error! {
         InvalidSignedHeader
             |_| { format_args!("invalid signed header") },
 
+        CommitHeaderMismatch
+            {
+                header_hash: crate::Hash,
+                commit_hash: crate::Hash,
+            }
+            | e | {
+                format_args!("commit is for block {} instead of header {}",
+                    e.commit_hash, e.header_hash)
+            },
+
+        InvalidChainLink
+            { reason: String }
+            | e | { format_args!("invalid chain link: {}", e.reason) },
+
+        InvalidCommit
+            { reason: String }
+            | e | { format_args!("invalid commit: {}", e.reason) },
+
+        InvalidBlockIdParts
+            |_| { format_args!("block id must have both a hash and a part set header, or neither") },
+
+        InvalidChainId
+            { reason: String }
+            | e | { format_args!("invalid chain id: {}", e.reason) },
+
         InvalidEvidence
             |_| { format_args!("invalid evidence") },
 
@@ -222,6 +247,9 @@ define_error! {
         TrustThresholdTooSmall
             |_| { "trust threshold too small (must be >= 1/3)" },
 
+        ZeroAbsoluteTrustThreshold
+            |_| { "absolute trust threshold cannot be 0" },
+
         NegativeProofTotal
             [ DisplayOnly<TryFromIntError> ]
             |_| { "negative number of items in proof" },
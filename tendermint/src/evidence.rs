@@ -347,6 +347,10 @@ tendermint_pb_modules! {
 /// Duration is a wrapper around core::time::Duration
 /// essentially, to keep the usages look cleaner
 /// i.e. you can avoid using serde annotations everywhere
+///
+/// It is serialized as a string holding a number of nanoseconds, e.g. the
+/// `trusting_period` of light client test fixtures, and is also available
+/// as `tendermint::Duration`.
 /// Todo: harmonize google::protobuf::Duration, core::time::Duration and this. Too many structs.
 /// <https://github.com/informalsystems/tendermint-rs/issues/741>
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -358,6 +362,12 @@ impl From<Duration> for core::time::Duration {
     }
 }
 
+impl From<core::time::Duration> for Duration {
+    fn from(d: core::time::Duration) -> Duration {
+        Duration(d)
+    }
+}
+
 impl Protobuf<RawDuration> for Duration {}
 
 impl TryFrom<RawDuration> for Duration {
@@ -380,3 +390,34 @@ impl From<Duration> for RawDuration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(nanos: &str) -> core::time::Duration {
+        serde_json::from_str::<Duration>(&format!("\"{nanos}\""))
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn duration_from_nanosecond_strings() {
+        assert_eq!(parse("0"), core::time::Duration::ZERO);
+
+        // The usual two week trusting period
+        assert_eq!(
+            parse("1209600000000000"),
+            core::time::Duration::from_secs(14 * 24 * 60 * 60)
+        );
+
+        let max = parse("18446744073709551615");
+        assert_eq!(max, core::time::Duration::from_nanos(u64::MAX));
+        assert_eq!(
+            serde_json::to_string(&Duration::from(max)).unwrap(),
+            "\"18446744073709551615\""
+        );
+
+        assert!(serde_json::from_str::<Duration>("\"18446744073709551616\"").is_err());
+    }
+}
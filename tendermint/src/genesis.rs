@@ -25,9 +25,52 @@ pub struct Genesis<AppState = serde_json::Value> {
     pub validators: Vec<validator::Info>,
 
     /// App hash
-    #[serde(with = "serializers::apphash")]
+    #[serde(with = "serializers::apphash_hex")]
     pub app_hash: AppHash,
 
     /// App state
     pub app_state: AppState,
 }
+
+impl<AppState> Genesis<AppState> {
+    /// The validator set the chain starts with at its initial height.
+    pub fn validator_set(&self) -> validator::Set {
+        validator::Set::without_proposer(self.validators.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn genesis() -> Genesis<Option<serde_json::Value>> {
+        serde_json::from_str(include_str!("../tests/support/serialization/genesis.json")).unwrap()
+    }
+
+    #[test]
+    fn deserialize_genesis() {
+        let genesis = genesis();
+        assert_eq!(genesis.chain_id.as_str(), "dockerchain");
+        assert_eq!(genesis.initial_height, 1);
+        assert!(genesis.app_hash.as_bytes().is_empty());
+        assert_eq!(genesis.validators.len(), 1);
+        assert!(genesis.app_state.is_none());
+
+        let json = serde_json::to_string(&genesis).unwrap();
+        let roundtrip: Genesis<Option<serde_json::Value>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtrip.chain_id, genesis.chain_id);
+        assert_eq!(roundtrip.genesis_time, genesis.genesis_time);
+        assert_eq!(roundtrip.validators, genesis.validators);
+    }
+
+    #[test]
+    fn genesis_validator_set() {
+        let genesis = genesis();
+        let validator_set = genesis.validator_set();
+
+        assert_eq!(validator_set.validators(), &genesis.validators);
+        assert_eq!(validator_set.total_voting_power().value(), 10);
+        let address = "DD8A65495B6240145764A74E78CF203D51510371".parse().unwrap();
+        assert!(validator_set.validator(&address).is_some());
+    }
+}
@@ -10,7 +10,7 @@ use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 use tendermint_proto::Protobuf;
 
-use crate::{error::Error, prelude::*};
+use crate::{error::Error, prelude::*, serializers::base64};
 
 /// Output size for the SHA-256 hash function
 pub const SHA256_HASH_SIZE: usize = 32;
@@ -130,6 +130,8 @@ impl Hash {
     }
 
     /// Convenience function to check for Hash::None
+    ///
+    /// Note that an all-zeros SHA-256 hash is not empty.
     pub fn is_empty(&self) -> bool {
         self == &Hash::None
     }
@@ -155,11 +157,13 @@ impl Display for Hash {
     }
 }
 
+/// Parses a SHA-256 hash from hexadecimal in either case, or `Hash::None`
+/// from an empty string.
 impl FromStr for Hash {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
-        Self::from_hex_upper(Algorithm::Sha256, s)
+        Self::from_hex_upper(Algorithm::Sha256, &s.to_ascii_uppercase())
     }
 }
 
@@ -182,6 +186,9 @@ impl Serialize for Hash {
 }
 
 /// Serialization/deserialization for `Hash` that allows for empty hashes.
+///
+/// The empty string is mapped to `Hash::None`, which the default `Deserialize`
+/// implementation of `Hash` rejects.
 pub mod allow_empty {
     use super::*;
 
@@ -253,6 +260,17 @@ impl AppHash {
             .map_err(Error::subtle_encoding)?;
         Ok(AppHash(h))
     }
+
+    /// Decode an `AppHash` from hexadecimal in either case
+    pub fn from_hex(s: &str) -> Result<Self, Error> {
+        Self::from_hex_upper(&s.to_ascii_uppercase())
+    }
+
+    /// Decode an `AppHash` from base64
+    pub fn from_base64(s: &str) -> Result<Self, Error> {
+        let h = base64::decode(s.as_bytes()).map_err(Error::subtle_encoding)?;
+        Ok(AppHash(h))
+    }
 }
 
 impl AsRef<[u8]> for AppHash {
@@ -288,3 +306,118 @@ impl FromStr for AppHash {
         Self::from_hex_upper(s)
     }
 }
+
+/// Deserializes from upper-case hexadecimal, or `null` for an empty app hash.
+///
+/// Fields encoded differently, e.g. in base64, should select their format
+/// with `#[serde(with = "crate::serializers::apphash_base64")]`.
+impl<'de> Deserialize<'de> for AppHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serializers::apphash_hex::deserialize(deserializer)
+    }
+}
+
+/// Serializes as upper-case hexadecimal.
+impl Serialize for AppHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serializers::apphash_hex::serialize(self, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MaybeEmpty(#[serde(with = "allow_empty")] Hash);
+
+    const HEX: &str = "0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF";
+
+    #[test]
+    fn empty_hash_round_trip() {
+        let hash: MaybeEmpty = serde_json::from_str(r#""""#).unwrap();
+        assert_eq!(hash.0, Hash::None);
+        assert!(hash.0.is_empty());
+        assert_eq!(serde_json::to_string(&hash).unwrap(), r#""""#);
+
+        // Empty hashes are rejected unless explicitly allowed
+        assert!(serde_json::from_str::<Hash>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn lower_case_hash_round_trip() {
+        let lower = format!("\"{}\"", HEX.to_ascii_lowercase());
+        let hash: Hash = serde_json::from_str(&lower).unwrap();
+        assert_eq!(hash, Hash::from_hex_upper(Algorithm::Sha256, HEX).unwrap());
+
+        // Hashes are always serialized in upper case
+        let serialized = serde_json::to_string(&hash).unwrap();
+        assert_eq!(serialized, format!("\"{HEX}\""));
+        assert_eq!(serde_json::from_str::<Hash>(&serialized).unwrap(), hash);
+    }
+
+    #[test]
+    fn zero_hash_round_trip() {
+        let zeros = format!("\"{}\"", "0".repeat(2 * SHA256_HASH_SIZE));
+        let hash: Hash = serde_json::from_str(&zeros).unwrap();
+        assert_eq!(hash, Hash::Sha256([0u8; SHA256_HASH_SIZE]));
+        assert!(!hash.is_empty());
+        assert_ne!(hash, Hash::None);
+        assert_eq!(serde_json::to_string(&hash).unwrap(), zeros);
+
+        let hash: MaybeEmpty = serde_json::from_str(&zeros).unwrap();
+        assert!(!hash.0.is_empty());
+    }
+
+    #[test]
+    fn app_hash_from_hex_and_base64() {
+        let bytes = vec![0xde, 0xad, 0xbe, 0xef, 0x01, 0x23];
+
+        let from_hex = AppHash::from_hex("DEADBEEF0123").unwrap();
+        let from_lower_hex = AppHash::from_hex("deadbeef0123").unwrap();
+        let from_base64 = AppHash::from_base64("3q2+7wEj").unwrap();
+        assert_eq!(from_hex.as_bytes(), bytes.as_slice());
+        assert_eq!(from_lower_hex, from_hex);
+        assert_eq!(from_base64, from_hex);
+        assert_eq!(from_hex.to_string(), "DEADBEEF0123");
+    }
+
+    #[test]
+    fn app_hash_serde() {
+        let expected = AppHash::try_from(vec![0xde, 0xad, 0xbe, 0xef, 0x01, 0x23]).unwrap();
+
+        let app_hash: AppHash = serde_json::from_str(r#""DEADBEEF0123""#).unwrap();
+        assert_eq!(app_hash, expected);
+        assert_eq!(
+            serde_json::to_string(&expected).unwrap(),
+            r#""DEADBEEF0123""#
+        );
+
+        let empty: AppHash = serde_json::from_str("null").unwrap();
+        assert_eq!(empty, AppHash::default());
+        let empty: AppHash = serde_json::from_str("\"\"").unwrap();
+        assert_eq!(empty, AppHash::default());
+
+        // The default encoding is hex only; base64 is never guessed
+        assert!(serde_json::from_str::<AppHash>(r#""3q2+7wEj""#).is_err());
+        assert!(serde_json::from_str::<AppHash>(r#""not an app hash!""#).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Base64AppHash(#[serde(with = "crate::serializers::apphash_base64")] AppHash);
+
+    #[test]
+    fn app_hash_base64_serde() {
+        let app_hash: Base64AppHash = serde_json::from_str(r#""3q2+7wEj""#).unwrap();
+        assert_eq!(app_hash.0, AppHash::from_hex("DEADBEEF0123").unwrap());
+        assert_eq!(serde_json::to_string(&app_hash).unwrap(), r#""3q2+7wEj""#);
+
+        // Valid hex is still decoded as base64 when the field says so
+        let app_hash: Base64AppHash = serde_json::from_str(r#""AAAAAAAA""#).unwrap();
+        assert_eq!(app_hash.0.as_bytes(), [0u8; 6]);
+        let app_hash: AppHash = serde_json::from_str(r#""AAAAAAAA""#).unwrap();
+        assert_eq!(app_hash.as_bytes(), [0xaa; 4]);
+    }
+}
@@ -66,6 +66,7 @@ mod test;
 pub use crate::{
     block::Block,
     error::Error,
+    evidence::Duration,
     genesis::Genesis,
     hash::{AppHash, Hash},
     moniker::Moniker,
@@ -12,10 +12,10 @@ pub use pub_key_response::PubKeyResponse;
 use core::{cmp::Ordering, fmt, str::FromStr};
 use serde::{de, ser, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
-use subtle_encoding::{base64, bech32, hex};
+use subtle_encoding::{bech32, hex};
 
 pub use crate::crypto::ed25519::VerificationKey as Ed25519;
-use crate::{error::Error, prelude::*};
+use crate::{error::Error, prelude::*, serializers::base64};
 
 // Note:On the golang side this is generic in the sense that it could everything that implements
 // github.com/tendermint/tendermint/crypto.PubKey
@@ -231,6 +231,22 @@ impl PublicKey {
     pub fn to_hex(self) -> String {
         String::from_utf8(hex::encode_upper(self.to_bytes())).unwrap()
     }
+
+    /// Verify a signature over the given message with this key, using the
+    /// default pure Rust verifier for whichever scheme the key belongs to.
+    ///
+    /// A signature produced with a different scheme than the key's is
+    /// rejected as malformed or as failing verification.
+    #[cfg(feature = "rust-crypto")]
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        signature: &crate::Signature,
+    ) -> Result<(), crate::crypto::signature::Error> {
+        use crate::crypto::signature::Verifier as _;
+
+        crate::crypto::default::signature::Verifier::verify(*self, msg, signature)
+    }
 }
 
 impl From<Ed25519> for PublicKey {
@@ -424,6 +440,49 @@ mod tests {
     const EXAMPLE_CONSENSUS_KEY: &str =
         "4A25C6640A1F72B9C975338294EF51B6D1C33158BB6ECBA69FBC3FB5A33C9DCE";
 
+    #[cfg(feature = "rust-crypto")]
+    fn ed25519_signed(msg: &[u8]) -> (PublicKey, crate::Signature) {
+        let signing_key = ed25519_consensus::SigningKey::from([7u8; 32]);
+        let public_key = PublicKey::from(signing_key.verification_key());
+        (public_key, signing_key.sign(msg).into())
+    }
+
+    #[cfg(feature = "secp256k1")]
+    fn secp256k1_signed(msg: &[u8]) -> (PublicKey, crate::Signature) {
+        use k256::ecdsa::signature::Signer;
+
+        let signing_key = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let public_key = PublicKey::from(*signing_key.verifying_key());
+        let signature: k256::ecdsa::Signature = signing_key.sign(msg);
+        (public_key, signature.into())
+    }
+
+    #[cfg(feature = "rust-crypto")]
+    #[test]
+    fn test_verify_ed25519() {
+        let (public_key, signature) = ed25519_signed(b"hello");
+        assert!(public_key.verify(b"hello", &signature).is_ok());
+        assert!(public_key.verify(b"goodbye", &signature).is_err());
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn test_verify_secp256k1() {
+        let (public_key, signature) = secp256k1_signed(b"hello");
+        assert!(public_key.verify(b"hello", &signature).is_ok());
+        assert!(public_key.verify(b"goodbye", &signature).is_err());
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn test_verify_rejects_mismatched_scheme() {
+        let (ed25519_key, ed25519_signature) = ed25519_signed(b"hello");
+        let (secp256k1_key, secp256k1_signature) = secp256k1_signed(b"hello");
+
+        assert!(ed25519_key.verify(b"hello", &secp256k1_signature).is_err());
+        assert!(secp256k1_key.verify(b"hello", &ed25519_signature).is_err());
+    }
+
     #[test]
     fn test_consensus_serialization() {
         let example_key = TendermintKey::ConsensusKey(
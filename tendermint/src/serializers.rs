@@ -7,8 +7,10 @@
 //! risk.
 pub use tendermint_proto::serializers::*;
 
+#[deprecated(since = "0.37.0", note = "use `apphash_hex` instead")]
 pub mod apphash;
 pub mod apphash_base64;
+pub mod apphash_hex;
 pub mod hash;
 pub mod option_hash;
 pub mod time;
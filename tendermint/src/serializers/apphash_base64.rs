@@ -1,11 +1,10 @@
-//! AppHash serialization with validation
+//! AppHash serialization to and from base64
 
 use alloc::borrow::Cow;
 
 use serde::{de, Deserialize, Deserializer, Serializer};
-use subtle_encoding::base64;
 
-use crate::{prelude::*, AppHash};
+use crate::{prelude::*, serializers::base64, AppHash};
 
 /// Deserialize a base64-encoded string into an [`AppHash`]
 pub fn deserialize<'de, D>(deserializer: D) -> Result<AppHash, D::Error>
@@ -127,6 +127,19 @@ impl Time {
         self.0.assume_utc().unix_timestamp_nanos()
     }
 
+    /// Return the seconds and nanoseconds of the protobuf `Timestamp`
+    /// representation, as encoded in the sign bytes of votes.
+    pub fn to_protobuf_timestamp(&self) -> (i64, i32) {
+        let Timestamp { seconds, nanos } = (*self).into();
+        (seconds, nanos)
+    }
+
+    /// Construct a [`Time`] from the seconds and nanoseconds of a protobuf
+    /// `Timestamp`, failing unless `nanos` is in the range `0..1_000_000_000`.
+    pub fn from_protobuf_timestamp(seconds: i64, nanos: i32) -> Result<Self, Error> {
+        Timestamp { seconds, nanos }.try_into()
+    }
+
     /// Computes `self + duration`, returning `None` if an overflow occurred.
     pub fn checked_add(self, duration: Duration) -> Option<Self> {
         let duration = duration.try_into().ok()?;
@@ -341,6 +354,25 @@ mod tests {
             let e = res.unwrap_err();
             assert!(matches!(e.detail(), ErrorDetail::TimestampNanosOutOfRange(_)))
         }
+
+        #[test]
+        fn protobuf_timestamp_round_trip(datetime in pbt::time::arb_protobuf_safe_datetime()) {
+            let t: Time = datetime.try_into().unwrap();
+            let (seconds, nanos) = t.to_protobuf_timestamp();
+            assert_eq!(seconds, datetime.unix_timestamp());
+            assert_eq!(nanos as u32, datetime.nanosecond());
+            assert_eq!(Time::from_protobuf_timestamp(seconds, nanos).unwrap(), t);
+        }
+
+        #[test]
+        fn from_protobuf_timestamp_rejects_out_of_range_nanos(
+            datetime in pbt::time::arb_protobuf_safe_datetime(),
+            nanos in prop_oneof![i32::MIN..0, 1_000_000_000..=i32::MAX],
+        ) {
+            let secs = datetime.unix_timestamp();
+            let e = Time::from_protobuf_timestamp(secs, nanos).unwrap_err();
+            assert!(matches!(e.detail(), ErrorDetail::TimestampNanosOutOfRange(_)))
+        }
     }
 
     fn duration_from_nanos(whole_nanos: u128) -> Duration {
@@ -434,4 +466,54 @@ mod tests {
             assert_eq!(t.checked_sub(d), None);
         }
     }
+
+    #[test]
+    fn protobuf_timestamp_nanos_bounds() {
+        let t = Time::from_protobuf_timestamp(1, 999_999_999).unwrap();
+        assert_eq!(t.to_protobuf_timestamp(), (1, 999_999_999));
+        assert!(Time::from_protobuf_timestamp(1, 1_000_000_000).is_err());
+        assert!(Time::from_protobuf_timestamp(1, -1).is_err());
+    }
+
+    #[test]
+    fn serde_preserves_nanoseconds() {
+        let json = r#""2021-01-07T20:25:56.123456789Z""#;
+        let time: Time = serde_json::from_str(json).unwrap();
+        assert_eq!(time.unix_timestamp_nanos(), 1_610_051_156_123_456_789);
+        assert_eq!(serde_json::to_string(&time).unwrap(), json);
+    }
+
+    #[test]
+    fn serde_trims_trailing_zeros() {
+        // Like Go's `time.RFC3339Nano`, trailing zeros of the fractional
+        // seconds are dropped without losing precision.
+        let time: Time = serde_json::from_str(r#""2021-01-07T20:25:58.035621000Z""#).unwrap();
+        assert_eq!(time.unix_timestamp_nanos(), 1_610_051_158_035_621_000);
+
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, r#""2021-01-07T20:25:58.035621Z""#);
+        assert_eq!(serde_json::from_str::<Time>(&json).unwrap(), time);
+    }
+
+    #[test]
+    fn serde_normalizes_offsets_to_utc() {
+        let utc: Time = serde_json::from_str(r#""2021-01-07T20:25:56.123456789Z""#).unwrap();
+        let offset: Time =
+            serde_json::from_str(r#""2021-01-07T22:25:56.123456789+02:00""#).unwrap();
+        assert_eq!(offset, utc);
+        assert_eq!(
+            serde_json::to_string(&offset).unwrap(),
+            r#""2021-01-07T20:25:56.123456789Z""#
+        );
+    }
+
+    #[test]
+    fn after_is_strict() {
+        let earlier = Time::from_unix_timestamp(1, 0).unwrap();
+        let later = Time::from_unix_timestamp(1, 1).unwrap();
+
+        assert!(later.after(earlier));
+        assert!(!earlier.after(earlier));
+        assert!(!earlier.after(later));
+    }
 }
@@ -12,6 +12,9 @@ use crate::{error::Error, prelude::*, serializers};
 pub trait TrustThreshold: Copy + Clone + Debug + Serialize + DeserializeOwned {
     /// Check whether the given signed voting power is sufficient according to
     /// this trust threshold against the given total voting power.
+    ///
+    /// Implementations must not overflow, even when the voting powers are
+    /// close to `u64::MAX`.
     fn is_enough_power(&self, signed_voting_power: u64, total_voting_power: u64) -> bool;
 }
 
@@ -57,7 +60,7 @@ impl TrustThresholdFraction {
         if denominator == 0 {
             return Err(Error::undefined_trust_threshold());
         }
-        if 3 * numerator < denominator {
+        if 3 * u128::from(numerator) < u128::from(denominator) {
             return Err(Error::trust_threshold_too_small());
         }
         Ok(Self {
@@ -96,7 +99,9 @@ impl From<TrustThresholdFraction> for RawTrustThresholdFraction {
 
 impl TrustThreshold for TrustThresholdFraction {
     fn is_enough_power(&self, signed_voting_power: u64, total_voting_power: u64) -> bool {
-        signed_voting_power * self.denominator > total_voting_power * self.numerator
+        // The products of two `u64` values always fit in a `u128`
+        u128::from(signed_voting_power) * u128::from(self.denominator)
+            > u128::from(total_voting_power) * u128::from(self.numerator)
     }
 }
 
@@ -112,6 +117,68 @@ impl Display for TrustThresholdFraction {
     }
 }
 
+/// AbsolutePower defines a minimum amount of signed voting power which is
+/// sufficient for a commit to be accepted going forward, regardless of the
+/// total voting power of the trusted validator set.
+///
+/// The signed voting power must be at least the threshold.
+///
+/// Note that, unlike a [`TrustThresholdFraction`] of at least 1/3, reaching an
+/// absolute amount of voting power does not by itself guarantee that at least
+/// one honest validator signed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "RawAbsolutePower", into = "RawAbsolutePower")]
+pub struct AbsolutePower(u64);
+
+impl AbsolutePower {
+    /// Instantiate an AbsolutePower if the given voting power is valid.
+    ///
+    /// The voting power is valid iff it is not 0.
+    pub fn new(voting_power: u64) -> Result<Self, Error> {
+        if voting_power == 0 {
+            return Err(Error::zero_absolute_trust_threshold());
+        }
+        Ok(Self(voting_power))
+    }
+
+    /// The minimum voting power which must have signed.
+    pub fn voting_power(&self) -> u64 {
+        self.0
+    }
+}
+
+impl TryFrom<RawAbsolutePower> for AbsolutePower {
+    type Error = Error;
+
+    fn try_from(value: RawAbsolutePower) -> Result<Self, Self::Error> {
+        Self::new(value.0)
+    }
+}
+
+impl From<AbsolutePower> for RawAbsolutePower {
+    fn from(p: AbsolutePower) -> Self {
+        Self(p.0)
+    }
+}
+
+impl TrustThreshold for AbsolutePower {
+    fn is_enough_power(&self, signed_voting_power: u64, _total_voting_power: u64) -> bool {
+        signed_voting_power >= self.0
+    }
+}
+
+impl Display for AbsolutePower {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Facilitates validation of [`AbsolutePower`] instances when deserializing
+/// them.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RawAbsolutePower(#[serde(with = "serializers::from_str")] u64);
+
 /// Facilitates validation of [`TrustThresholdFraction`] instances when
 /// deserializing them.
 #[derive(Serialize, Deserialize)]
@@ -137,6 +204,38 @@ mod test {
         serde_json::from_str::<TrustThresholdFraction>(&json)
     }
 
+    #[test]
+    fn absolute_power_ignores_total_power() {
+        let absolute = AbsolutePower::new(10_000_000).unwrap();
+        let fraction = TrustThresholdFraction::ONE_THIRD;
+
+        // Half of the total power signed, but less than the absolute minimum
+        assert!(fraction.is_enough_power(5_000_000, 10_000_000));
+        assert!(!absolute.is_enough_power(5_000_000, 10_000_000));
+
+        // The absolute minimum signed, but only a tenth of the total power
+        assert!(!fraction.is_enough_power(10_000_000, 100_000_000));
+        assert!(absolute.is_enough_power(10_000_000, 100_000_000));
+
+        // Unlike fractions, exactly reaching the threshold is enough
+        assert!(absolute.is_enough_power(10_000_000, 20_000_000));
+        assert!(!absolute.is_enough_power(9_999_999, 20_000_000));
+        assert!(!fraction.is_enough_power(10, 30));
+    }
+
+    #[test]
+    fn absolute_power_rejects_zero() {
+        assert!(AbsolutePower::new(0).is_err());
+        assert!(serde_json::from_str::<AbsolutePower>("\"0\"").is_err());
+    }
+
+    #[test]
+    fn absolute_power_serde() {
+        let absolute: AbsolutePower = serde_json::from_str("\"10000000\"").unwrap();
+        assert_eq!(absolute, AbsolutePower::new(10_000_000).unwrap());
+        assert_eq!(serde_json::to_string(&absolute).unwrap(), "\"10000000\"");
+    }
+
     prop_compose! {
         // num < denom <= 3*num
         fn arb_correct_frac(num: u64)(denom in (num+1)..=(3*num)) -> (u64, u64) {
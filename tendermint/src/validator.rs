@@ -1,6 +1,8 @@
 //! Tendermint validators
 
-use serde::{Deserialize, Serialize};
+use alloc::collections::BTreeMap;
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use tendermint_proto::v0_38::types::{
     SimpleValidator as RawSimpleValidator, ValidatorSet as RawValidatorSet,
 };
@@ -14,9 +16,25 @@ use crate::{
     merkle::{self, MerkleHash},
     prelude::*,
     public_key::deserialize_public_key,
+    trust_threshold::TrustThreshold,
     vote, Error, PublicKey, Signature,
 };
 
+/// The proposer priorities are kept within this factor of the total voting
+/// power of each other, as in Tendermint.
+const PRIORITY_WINDOW_SIZE_FACTOR: i64 = 2;
+
+/// Version of Tendermint determining how validators are encoded in the leaves
+/// of the Merkle tree of a validator set hash.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TmVersion {
+    /// Tendermint 0.33 and earlier, which encode validators with Amino
+    V0_33,
+    /// Tendermint 0.34 and later, which encode validators with Protobuf
+    #[default]
+    V0_34,
+}
+
 /// Validator set contains a vector of validators
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(try_from = "RawValidatorSet")]
@@ -24,26 +42,67 @@ pub struct Set {
     validators: Vec<Info>,
     proposer: Option<Info>,
     total_voting_power: vote::Power,
+    // Position of each validator in `validators`, by address.
+    #[serde(skip)]
+    index: BTreeMap<account::Id, usize>,
 }
 
 impl Set {
     pub const MAX_TOTAL_VOTING_POWER: u64 = (i64::MAX / 8) as u64;
 
     /// Constructor
+    ///
+    /// The validators are sorted into the canonical order required for the
+    /// set to hash identically to Tendermint's, i.e. by voting power,
+    /// descending, then by address, ascending.
+    ///
+    /// ## Panics
+    /// If the total voting power of the given validators exceeds
+    /// [`Set::MAX_TOTAL_VOTING_POWER`]. Use [`Set::try_new`] when the
+    /// validators come from an untrusted source.
     pub fn new(validators: Vec<Info>, proposer: Option<Info>) -> Set {
-        Self::try_from_parts(validators, proposer, 0).unwrap()
+        Self::try_new(validators, proposer).unwrap()
+    }
+
+    /// Fallible constructor, returning an error if the total voting power of
+    /// the given validators overflows or exceeds [`Set::MAX_TOTAL_VOTING_POWER`].
+    pub fn try_new(validators: Vec<Info>, proposer: Option<Info>) -> Result<Set, Error> {
+        Self::try_from_parts(validators, proposer, 0)
+    }
+
+    /// Constructor for callers which guarantee that the given validators are
+    /// already in canonical order, skipping the sort done by [`Set::new`].
+    ///
+    /// The ordering is not checked: a set constructed from mis-ordered
+    /// validators will not hash identically to Tendermint's.
+    ///
+    /// ## Panics
+    /// If the total voting power of the given validators exceeds
+    /// [`Set::MAX_TOTAL_VOTING_POWER`].
+    pub fn from_sorted_unchecked(validators: Vec<Info>, proposer: Option<Info>) -> Set {
+        Self::try_from_sorted_parts(validators, proposer, 0).unwrap()
     }
 
     fn try_from_parts(
         mut validators: Vec<Info>,
         proposer: Option<Info>,
         unvalidated_total_voting_power: i64,
+    ) -> Result<Set, Error> {
+        Self::sort_validators(&mut validators);
+        Self::try_from_sorted_parts(validators, proposer, unvalidated_total_voting_power)
+    }
+
+    fn try_from_sorted_parts(
+        validators: Vec<Info>,
+        proposer: Option<Info>,
+        unvalidated_total_voting_power: i64,
     ) -> Result<Set, Error> {
         // Compute the total voting power
         let total_voting_power = validators
             .iter()
             .map(|v| v.power.value())
-            .fold(0u64, |acc, v| acc.saturating_add(v));
+            .try_fold(0u64, |acc, v| acc.checked_add(v))
+            .ok_or_else(Error::total_voting_power_overflow)?;
 
         if total_voting_power > Self::MAX_TOTAL_VOTING_POWER {
             return Err(Error::total_voting_power_overflow());
@@ -61,12 +120,17 @@ impl Set {
             }
         }
 
-        Self::sort_validators(&mut validators);
+        let index = validators
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.address, i))
+            .collect();
 
         Ok(Set {
             validators,
             proposer,
             total_voting_power,
+            index,
         })
     }
 
@@ -92,6 +156,45 @@ impl Set {
         Ok(Self::new(validators, Some(proposer)))
     }
 
+    /// Add the given validators to the set, keeping it in canonical order.
+    ///
+    /// A validator whose address is already in the set replaces the existing
+    /// entry, so that overlapping pages of validators fetched through RPC can
+    /// be merged incrementally.
+    ///
+    /// ## Panics
+    /// If the total voting power of the resulting set exceeds
+    /// [`Set::MAX_TOTAL_VOTING_POWER`]. Use [`Set::try_extend`] when the
+    /// validators come from an untrusted source.
+    pub fn extend(&mut self, more: impl IntoIterator<Item = Info>) {
+        self.try_extend(more).unwrap()
+    }
+
+    /// Fallible version of [`Set::extend`], returning an error and leaving the
+    /// set unchanged if the total voting power of the resulting set overflows
+    /// or exceeds [`Set::MAX_TOTAL_VOTING_POWER`].
+    pub fn try_extend(&mut self, more: impl IntoIterator<Item = Info>) -> Result<(), Error> {
+        let mut by_address: BTreeMap<account::Id, Info> = self
+            .validators
+            .iter()
+            .map(|v| (v.address, v.clone()))
+            .collect();
+        for v in more {
+            by_address.insert(v.address, v);
+        }
+
+        // Keep the proposer consistent with its entry in the set.
+        let proposer = self.proposer.as_ref().map(|p| {
+            by_address
+                .get(&p.address)
+                .cloned()
+                .unwrap_or_else(|| p.clone())
+        });
+
+        *self = Self::try_new(by_address.into_values().collect(), proposer)?;
+        Ok(())
+    }
+
     /// Get Info of the underlying validators.
     pub fn validators(&self) -> &Vec<Info> {
         &self.validators
@@ -102,7 +205,101 @@ impl Set {
         &self.proposer
     }
 
+    /// Run Tendermint's proposer selection the given number of times, i.e.
+    /// once per round, updating the proposer priorities of the validators,
+    /// and return the selected proposer, which also becomes the proposer of
+    /// the set.
+    ///
+    /// Before selecting, the priorities are rescaled so that they are at
+    /// most twice the total voting power apart, and centered around zero, so
+    /// that they cannot overflow.
+    ///
+    /// ## Panics
+    /// If the set is empty or `times` is zero.
+    pub fn increment_proposer_priority(&mut self, times: u32) -> &Info {
+        assert!(!self.is_empty(), "empty validator set");
+        assert!(
+            times > 0,
+            "cannot call increment_proposer_priority with zero times"
+        );
+
+        // Cannot overflow, as the total is at most `MAX_TOTAL_VOTING_POWER`
+        let total_voting_power = self.total_voting_power.value() as i64;
+
+        self.rescale_priorities(PRIORITY_WINDOW_SIZE_FACTOR * total_voting_power);
+        self.shift_by_avg_proposer_priority();
+
+        let mut proposer = 0;
+        for _ in 0..times {
+            for v in &mut self.validators {
+                v.proposer_priority = v
+                    .proposer_priority
+                    .value()
+                    .saturating_add(v.power() as i64)
+                    .into();
+            }
+            proposer = self.index_of_most_priority();
+            let v = &mut self.validators[proposer];
+            v.proposer_priority = v
+                .proposer_priority
+                .value()
+                .saturating_sub(total_voting_power)
+                .into();
+        }
+
+        self.proposer = Some(self.validators[proposer].clone());
+        &self.validators[proposer]
+    }
+
+    /// Divide the priorities by the same ratio, so that the difference
+    /// between the highest and lowest ones is at most `diff_max`.
+    fn rescale_priorities(&mut self, diff_max: i64) {
+        let priorities = self.validators.iter().map(|v| v.proposer_priority.value());
+        let (Some(max), Some(min)) = (priorities.clone().max(), priorities.min()) else {
+            return;
+        };
+        let diff = i128::from(max) - i128::from(min);
+        let diff_max = i128::from(diff_max);
+        if diff_max <= 0 || diff <= diff_max {
+            return;
+        }
+
+        let ratio = (diff + diff_max - 1) / diff_max;
+        for v in &mut self.validators {
+            // Cannot overflow, as the ratio is at least 2
+            v.proposer_priority = ((i128::from(v.proposer_priority.value()) / ratio) as i64).into();
+        }
+    }
+
+    /// Center the priorities around zero by subtracting their average.
+    fn shift_by_avg_proposer_priority(&mut self) {
+        let sum: i128 = self
+            .validators
+            .iter()
+            .map(|v| i128::from(v.proposer_priority.value()))
+            .sum();
+        // The average of `i64` values fits in an `i64`
+        let avg = sum.div_euclid(self.validators.len() as i128) as i64;
+        for v in &mut self.validators {
+            v.proposer_priority = v.proposer_priority.value().saturating_sub(avg).into();
+        }
+    }
+
+    /// Index of the validator with the highest priority, the one with the
+    /// lowest address winning ties.
+    fn index_of_most_priority(&self) -> usize {
+        self.validators
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, v)| (v.proposer_priority, core::cmp::Reverse(v.address)))
+            .map(|(i, _)| i)
+            .unwrap_or_default()
+    }
+
     /// Get total voting power
+    ///
+    /// The total is computed with overflow checks when the set is constructed,
+    /// and is guaranteed not to exceed [`Set::MAX_TOTAL_VOTING_POWER`].
     pub fn total_voting_power(&self) -> vote::Power {
         self.total_voting_power
     }
@@ -113,12 +310,54 @@ impl Set {
         vals.sort_by_key(|v| (core::cmp::Reverse(v.power), v.address));
     }
 
-    /// Returns the validator with the given Id if its in the Set.
-    pub fn validator(&self, val_id: account::Id) -> Option<Info> {
-        self.validators
-            .iter()
-            .find(|val| val.address == val_id)
-            .cloned()
+    /// Returns the validator with the given address if it is in the set.
+    ///
+    /// The lookup goes through an index built when the set is constructed,
+    /// and takes logarithmic rather than linear time in the number of
+    /// validators.
+    pub fn validator(&self, address: &account::Id) -> Option<&Info> {
+        self.index.get(address).map(|&i| &self.validators[i])
+    }
+
+    /// Returns whether a validator with the given address is in the set.
+    pub fn contains(&self, address: &account::Id) -> bool {
+        self.index.contains_key(address)
+    }
+
+    /// Get the number of validators in the set.
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// Returns whether the set has no validators.
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// Iterate over the validators of the set, in canonical order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Info> {
+        self.validators.iter()
+    }
+
+    /// Returns the smallest set of validators, taken by decreasing voting
+    /// power, whose combined voting power meets the given threshold of the
+    /// total voting power, e.g. the validators able to halt the chain for a
+    /// threshold of 1/3.
+    ///
+    /// The fewer validators are returned, the more concentrated the voting
+    /// power is. If the threshold cannot be met, all validators are returned.
+    pub fn validators_for_power_fraction(&self, threshold: impl TrustThreshold) -> Vec<&Info> {
+        let total = self.total_voting_power.value();
+        let mut power = 0u64;
+        let mut validators = Vec::new();
+        for validator in &self.validators {
+            if threshold.is_enough_power(power, total) {
+                break;
+            }
+            power = power.saturating_add(validator.power());
+            validators.push(validator);
+        }
+        validators
     }
 
     /// Compute the hash of this validator set.
@@ -129,13 +368,29 @@ impl Set {
 
     /// Hash this header with a SHA256 hasher provided by a crypto provider.
     pub fn hash_with<H>(&self) -> Hash
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        self.hash_for_version_with::<H>(TmVersion::default())
+    }
+
+    /// Compute the hash of this validator set as computed by the given
+    /// version of Tendermint, eg. to verify headers of older chains.
+    #[cfg(feature = "rust-crypto")]
+    pub fn hash_for_version(&self, version: TmVersion) -> Hash {
+        self.hash_for_version_with::<crate::crypto::default::Sha256>(version)
+    }
+
+    /// Hash this validator set as the given version of Tendermint does, with
+    /// a SHA256 hasher provided by a crypto provider.
+    pub fn hash_for_version_with<H>(&self, version: TmVersion) -> Hash
     where
         H: MerkleHash + Sha256 + Default,
     {
         let validator_bytes: Vec<Vec<u8>> = self
             .validators()
             .iter()
-            .map(|validator| validator.hash_bytes())
+            .map(|validator| validator.hash_bytes_for_version(version))
             .collect();
 
         Hash::Sha256(merkle::simple_hash_from_byte_vectors::<H>(&validator_bytes))
@@ -161,7 +416,7 @@ pub struct Info {
     pub name: Option<String>,
 
     /// Validator proposer priority
-    #[serde(skip)]
+    #[serde(default)]
     pub proposer_priority: ProposerPriority,
 }
 
@@ -223,6 +478,45 @@ impl Info {
     pub fn hash_bytes(&self) -> Vec<u8> {
         Protobuf::<RawSimpleValidator>::encode_vec(SimpleValidator::from(self))
     }
+
+    /// Returns the leaves of the Merkle tree, as encoded by the given version
+    /// of Tendermint.
+    pub fn hash_bytes_for_version(&self, version: TmVersion) -> Vec<u8> {
+        match version {
+            TmVersion::V0_33 => SimpleValidator::from(self).amino_encode(),
+            TmVersion::V0_34 => self.hash_bytes(),
+        }
+    }
+}
+
+impl SimpleValidator {
+    /// Amino encoding of the validator, as hashed by Tendermint 0.33.
+    ///
+    /// The public key is encoded as an Amino interface, prefixed with the
+    /// disambiguation bytes of its registered concrete type.
+    fn amino_encode(&self) -> Vec<u8> {
+        use prost::encoding::encode_varint;
+
+        let (prefix, key) = match self.pub_key {
+            PublicKey::Ed25519(_) => ([0x16, 0x24, 0xde, 0x64], self.pub_key.to_bytes()),
+            #[cfg(feature = "secp256k1")]
+            PublicKey::Secp256k1(_) => ([0xeb, 0x5a, 0xe9, 0x87], self.pub_key.to_bytes()),
+        };
+
+        let mut pub_key = prefix.to_vec();
+        encode_varint(key.len() as u64, &mut pub_key);
+        pub_key.extend_from_slice(&key);
+
+        let mut bytes = Vec::with_capacity(pub_key.len() + 12);
+        // Field 1, length-delimited
+        bytes.push(0x0a);
+        encode_varint(pub_key.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&pub_key);
+        // Field 2, varint
+        bytes.push(0x10);
+        encode_varint(self.voting_power.value(), &mut bytes);
+        bytes
+    }
 }
 
 // Todo: Is there more knowledge/restrictions about proposerPriority?
@@ -249,6 +543,22 @@ impl ProposerPriority {
     }
 }
 
+impl<'de> Deserialize<'de> for ProposerPriority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ProposerPriority(
+            String::deserialize(deserializer)?
+                .parse::<i64>()
+                .map_err(|e| D::Error::custom(format!("{e}")))?,
+        ))
+    }
+}
+
+impl Serialize for ProposerPriority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.to_string().serialize(serializer)
+    }
+}
+
 /// A change to the validator set.
 ///
 /// Used to inform Tendermint of changes to the validator set.
@@ -265,6 +575,96 @@ pub struct Update {
     pub power: vote::Power,
 }
 
+/// Serialization/deserialization of a validator [`Set`] in the shape of the
+/// result of the RPC `/validators` endpoint, i.e. as an object with the
+/// `validators`, `count` and `total` fields.
+///
+/// This allows persisting validator sets fetched over RPC in the same format
+/// as they were received. The set's proposer is not part of this format, and
+/// is thus not preserved. A page of validators which does not contain the
+/// whole set, i.e. whose `count` is less than its `total`, is rejected.
+pub mod validators_response {
+    use super::*;
+    use crate::serializers;
+
+    #[derive(Serialize)]
+    struct ValidatorRef<'a> {
+        address: &'a account::Id,
+        pub_key: &'a PublicKey,
+        voting_power: vote::Power,
+        proposer_priority: ProposerPriority,
+    }
+
+    #[derive(Serialize)]
+    struct SetRef<'a> {
+        validators: Vec<ValidatorRef<'a>>,
+        #[serde(with = "serializers::from_str")]
+        count: usize,
+        #[serde(with = "serializers::from_str")]
+        total: usize,
+    }
+
+    #[derive(Deserialize)]
+    struct Validators {
+        validators: Vec<Info>,
+        #[serde(with = "serializers::from_str")]
+        count: usize,
+        #[serde(with = "serializers::from_str")]
+        total: usize,
+    }
+
+    /// Serialize a validator [`Set`] into a `/validators` result.
+    pub fn serialize<S>(value: &Set, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let validators = value
+            .validators()
+            .iter()
+            .map(|v| ValidatorRef {
+                address: &v.address,
+                pub_key: &v.pub_key,
+                voting_power: v.power,
+                proposer_priority: v.proposer_priority,
+            })
+            .collect::<Vec<_>>();
+        let count = validators.len();
+
+        SetRef {
+            validators,
+            count,
+            total: count,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a validator [`Set`] from a `/validators` result.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Set, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Validators {
+            validators,
+            count,
+            total,
+        } = Validators::deserialize(deserializer)?;
+
+        if count != validators.len() {
+            return Err(D::Error::custom(format!(
+                "count of {count} does not match the {} validators",
+                validators.len()
+            )));
+        }
+        if count != total {
+            return Err(D::Error::custom(format!(
+                "incomplete validator set: {count} of {total} validators"
+            )));
+        }
+
+        Set::try_new(validators, None).map_err(|e| D::Error::custom(format!("{e}")))
+    }
+}
+
 // =============================================================================
 // Protobuf conversions
 // =============================================================================
@@ -393,6 +793,7 @@ mod tests {
     #[cfg(feature = "rust-crypto")]
     mod crypto {
         use super::*;
+        use crate::error::ErrorDetail;
 
         // make a validator
         fn make_validator(pk: Vec<u8>, vp: u64) -> Info {
@@ -460,6 +861,7 @@ mod tests {
             let val_set = Set::without_proposer(vec![v1.clone(), v2.clone(), v3.clone()]);
             let hash = val_set.hash();
             assert_eq!(hash_expect, hash.as_bytes().to_vec());
+            assert_eq!(val_set.hash_for_version(TmVersion::V0_34), hash);
 
             let not_in_set = make_validator(
                 vec![
@@ -469,15 +871,345 @@ mod tests {
                 10_000_000_000_000_000,
             );
 
-            assert_eq!(val_set.validator(v1.address).unwrap(), v1);
-            assert_eq!(val_set.validator(v2.address).unwrap(), v2);
-            assert_eq!(val_set.validator(v3.address).unwrap(), v3);
-            assert_eq!(val_set.validator(not_in_set.address), None);
+            assert_eq!(val_set.validator(&v1.address).unwrap(), &v1);
+            assert_eq!(val_set.validator(&v2.address).unwrap(), &v2);
+            assert_eq!(val_set.validator(&v3.address).unwrap(), &v3);
+            assert_eq!(val_set.validator(&not_in_set.address), None);
             assert_eq!(
                 val_set.total_voting_power().value(),
                 148_151_478_422_287_875 + 158_095_448_483_785_107 + 770_561_664_770_006_272
             );
         }
+
+        #[test]
+        fn test_validator_set_hash_for_v0_33() {
+            // The validators of `test_validator_set`, whose leaves Tendermint
+            // 0.33 encodes with Amino, ie. with the public key prefixed by the
+            // `tendermint/PubKeyEd25519` disambiguation bytes
+            let v1 = make_validator(
+                vec![
+                    48, 163, 55, 132, 231, 147, 230, 163, 56, 158, 127, 218, 179, 139, 212, 103,
+                    218, 89, 122, 126, 229, 88, 84, 48, 32, 0, 185, 174, 63, 72, 203, 52,
+                ],
+                148_151_478_422_287_875,
+            );
+            let v2 = make_validator(
+                vec![
+                    54, 253, 174, 153, 121, 74, 145, 180, 111, 16, 214, 48, 193, 109, 104, 134, 55,
+                    162, 151, 16, 182, 114, 125, 135, 32, 195, 236, 248, 64, 112, 74, 101,
+                ],
+                158_095_448_483_785_107,
+            );
+            let v3 = make_validator(
+                vec![
+                    182, 205, 13, 86, 147, 27, 65, 49, 160, 118, 11, 180, 117, 35, 206, 35, 68, 19,
+                    27, 173, 69, 92, 204, 224, 200, 51, 249, 81, 105, 128, 112, 244,
+                ],
+                770_561_664_770_006_272,
+            );
+
+            let leaf = v1.hash_bytes_for_version(TmVersion::V0_33);
+            assert_eq!(leaf[..8], [0x0a, 0x25, 0x16, 0x24, 0xde, 0x64, 0x20, 48]);
+            assert_eq!(leaf[39], 0x10);
+            assert_eq!(v1.hash_bytes_for_version(TmVersion::V0_34), v1.hash_bytes());
+
+            // The hash printed by the Go program of `test_validator_set` when
+            // run against Tendermint 0.33, as expected by that test in
+            // tendermint-rs up to v0.16, before the switch to Tendermint 0.34
+            let hash_expect = vec![
+                42, 47, 127, 164, 35, 218, 191, 62, 25, 3, 139, 62, 216, 160, 90, 200, 179, 42,
+                233, 139, 186, 131, 40, 238, 38, 60, 235, 140, 53, 3, 239, 199,
+            ];
+
+            let val_set = Set::without_proposer(vec![v1, v2, v3]);
+            let hash = val_set.hash_for_version(TmVersion::V0_33);
+            assert_eq!(hash_expect, hash.as_bytes().to_vec());
+            assert_ne!(hash, val_set.hash());
+        }
+
+        #[test]
+        fn test_validator_lookup_by_address() {
+            let validators: Vec<Info> = (1..=10u8)
+                .map(|i| make_validator(vec![i; 32], u64::from(i % 3) + 1))
+                .collect();
+            let val_set = Set::without_proposer(validators.clone());
+
+            for v in &validators {
+                assert_eq!(val_set.validator(&v.address), Some(v));
+            }
+            assert_eq!(
+                val_set.validator(&account::Id::new([0; account::LENGTH])),
+                None
+            );
+
+            // The index is rebuilt along with the set
+            let raw: RawValidatorSet = val_set.clone().into();
+            let rebuilt = Set::try_from(raw).unwrap();
+            assert_eq!(rebuilt, val_set);
+            for v in &validators {
+                assert_eq!(rebuilt.validator(&v.address), Some(v));
+            }
+        }
+
+        #[test]
+        fn test_canonical_ordering() {
+            let validators: Vec<Info> = (1..=6u8)
+                .map(|i| make_validator(vec![i; 32], u64::from(i % 3) + 1))
+                .collect();
+
+            let mut sorted = validators.clone();
+            sorted.sort_by_key(|v| (core::cmp::Reverse(v.power), v.address));
+            assert_ne!(validators, sorted);
+
+            let from_unsorted = Set::without_proposer(validators.clone());
+            let from_sorted = Set::from_sorted_unchecked(sorted.clone(), None);
+            assert_eq!(from_unsorted.validators(), &sorted);
+            assert_eq!(from_unsorted.hash(), from_sorted.hash());
+            assert_eq!(from_unsorted, from_sorted);
+
+            // Mis-ordered validators passed to the unchecked constructor are kept as is
+            let misordered = Set::from_sorted_unchecked(validators.clone(), None);
+            assert_eq!(misordered.validators(), &validators);
+            assert_ne!(misordered.hash(), from_sorted.hash());
+        }
+
+        #[test]
+        fn test_introspection() {
+            let validators: Vec<Info> = (1..=4u8)
+                .map(|i| make_validator(vec![i; 32], u64::from(i % 2) + 1))
+                .collect();
+            let absent = make_validator(vec![5; 32], 1);
+
+            let set = Set::without_proposer(validators.clone());
+            assert_eq!(set.len(), 4);
+            assert!(!set.is_empty());
+            assert!(set.iter().eq(set.validators().iter()));
+            assert!(set.iter().zip(set.iter().skip(1)).all(|(a, b)| (
+                core::cmp::Reverse(a.power),
+                a.address
+            ) < (
+                core::cmp::Reverse(b.power),
+                b.address
+            )));
+
+            assert!(validators.iter().all(|v| set.contains(&v.address)));
+            assert!(!set.contains(&absent.address));
+
+            let empty = Set::without_proposer(vec![]);
+            assert_eq!(empty.len(), 0);
+            assert!(empty.is_empty());
+            assert_eq!(empty.iter().next(), None);
+        }
+
+        #[test]
+        fn test_validators_for_power_fraction() {
+            use crate::trust_threshold::TrustThresholdFraction;
+
+            // Two of five validators hold more than 1/3 of the voting power
+            let set = Set::without_proposer(
+                [(1, 15), (2, 30), (3, 20), (4, 15), (5, 20)]
+                    .into_iter()
+                    .map(|(i, vp)| make_validator(vec![i; 32], vp))
+                    .collect(),
+            );
+            let top = |n: usize| set.validators()[..n].iter().collect::<Vec<_>>();
+
+            let one_third = set.validators_for_power_fraction(TrustThresholdFraction::ONE_THIRD);
+            assert_eq!(one_third, top(2));
+            assert_eq!(one_third[0].power(), 30);
+            assert_eq!(one_third[1].power(), 20);
+
+            let two_thirds = set.validators_for_power_fraction(TrustThresholdFraction::TWO_THIRDS);
+            assert_eq!(two_thirds, top(3));
+
+            // Exactly half of the voting power does not meet a threshold of 1/2
+            let half = TrustThresholdFraction::new(1, 2).unwrap();
+            assert_eq!(set.validators_for_power_fraction(half), top(3));
+
+            // A threshold which cannot be met returns the whole set
+            let all = TrustThresholdFraction::new(1, 1).unwrap();
+            assert_eq!(set.validators_for_power_fraction(all), top(5));
+
+            let empty = Set::without_proposer(vec![]);
+            assert!(empty
+                .validators_for_power_fraction(TrustThresholdFraction::ONE_THIRD)
+                .is_empty());
+        }
+
+        // A validator with the given address, padded with zeroes, as in
+        // Tendermint's proposer selection tests.
+        fn validator_with_address(address: &[u8], vp: u64) -> Info {
+            let mut padded = [0; 20];
+            padded[..address.len()].copy_from_slice(address);
+            Info {
+                address: account::Id::new(padded),
+                ..make_validator(vec![address[address.len() - 1]; 32], vp)
+            }
+        }
+
+        // Mirrors `NewValidatorSet` in Tendermint, which selects a first
+        // proposer when constructing the set.
+        fn new_validator_set(validators: Vec<Info>) -> Set {
+            let mut set = Set::without_proposer(validators);
+            set.increment_proposer_priority(1);
+            set
+        }
+
+        // `TestProposerSelection1` in Tendermint's `types/validator_set_test.go`
+        #[test]
+        fn test_proposer_selection_sequence() {
+            let mut set = new_validator_set(vec![
+                validator_with_address(b"foo", 1000),
+                validator_with_address(b"bar", 300),
+                validator_with_address(b"baz", 330),
+            ]);
+
+            let mut proposers = vec![];
+            for _ in 0..99 {
+                let proposer = set.proposer().as_ref().unwrap().address;
+                let name = [b"foo", b"bar", b"baz"]
+                    .into_iter()
+                    .find(|name| proposer.as_bytes().starts_with(*name))
+                    .unwrap();
+                proposers.push(core::str::from_utf8(name).unwrap());
+                set.increment_proposer_priority(1);
+            }
+
+            let expected = "foo baz foo bar foo foo baz foo bar foo foo baz foo foo bar foo baz foo foo bar \
+                            foo foo baz foo bar foo foo baz foo bar foo foo baz foo foo bar foo baz foo foo bar \
+                            foo baz foo foo bar foo baz foo foo bar foo baz foo foo foo baz bar foo foo foo baz \
+                            foo bar foo foo baz foo bar foo foo baz foo bar foo foo baz foo bar foo foo baz foo \
+                            foo bar foo baz foo foo bar foo baz foo foo bar foo baz foo foo";
+            assert_eq!(proposers.join(" "), expected);
+        }
+
+        // `TestProposerSelection2` in Tendermint's `types/validator_set_test.go`
+        #[test]
+        fn test_proposer_selection_ties_and_repeats() {
+            let addresses: Vec<[u8; 1]> = vec![[0], [1], [2]];
+            let validators = |powers: [u64; 3]| -> Vec<Info> {
+                addresses
+                    .iter()
+                    .zip(powers)
+                    .map(|(address, vp)| validator_with_address(address, vp))
+                    .collect()
+            };
+            let proposer = |set: &Set| set.proposer().as_ref().unwrap().address.as_bytes()[0];
+
+            // When all voting power is the same, validators propose in order of addresses
+            let mut set = new_validator_set(validators([100, 100, 100]));
+            for i in 0..15 {
+                assert_eq!(proposer(&set), i % 3);
+                set.increment_proposer_priority(1);
+            }
+
+            // One validator has more than the others, but not enough to propose twice in a row
+            let mut set = new_validator_set(validators([100, 100, 400]));
+            assert_eq!(proposer(&set), 2);
+            assert_eq!(set.increment_proposer_priority(1).address.as_bytes()[0], 0);
+
+            // One validator has more than the others, and enough to propose twice in a row
+            let mut set = new_validator_set(validators([100, 100, 401]));
+            assert_eq!(proposer(&set), 2);
+            assert_eq!(set.increment_proposer_priority(1).address.as_bytes()[0], 2);
+            assert_eq!(set.increment_proposer_priority(1).address.as_bytes()[0], 0);
+        }
+
+        #[test]
+        fn test_proposer_priorities_stay_centered() {
+            let mut set = Set::without_proposer(vec![
+                make_validator(vec![1; 32], 1),
+                make_validator(vec![2; 32], 1000),
+            ]);
+            for v in &mut set.validators {
+                v.proposer_priority = if v.power() == 1 { i64::MAX } else { i64::MIN }.into();
+            }
+
+            set.increment_proposer_priority(10);
+
+            let total = set.total_voting_power().value() as i64;
+            let priorities: Vec<i64> = set.iter().map(|v| v.proposer_priority.value()).collect();
+            let spread = priorities.iter().max().unwrap() - priorities.iter().min().unwrap();
+            assert!(spread <= PRIORITY_WINDOW_SIZE_FACTOR * total + total);
+        }
+
+        #[test]
+        fn test_extend_merges_overlapping_pages() {
+            let validators: Vec<Info> = (1..=5u8)
+                .map(|i| make_validator(vec![i; 32], u64::from(i)))
+                .collect();
+
+            let mut set = Set::without_proposer(validators[..3].to_vec());
+            // The second page overlaps the first one, with an updated power.
+            let updated = make_validator(vec![3; 32], 10);
+            set.extend([
+                updated.clone(),
+                validators[3].clone(),
+                validators[4].clone(),
+            ]);
+
+            let expected = Set::without_proposer(vec![
+                validators[0].clone(),
+                validators[1].clone(),
+                updated.clone(),
+                validators[3].clone(),
+                validators[4].clone(),
+            ]);
+            assert_eq!(set, expected);
+            assert_eq!(set.len(), 5);
+            assert_eq!(set.validators()[0], updated);
+            assert_eq!(set.validator(&updated.address), Some(&updated));
+            assert_eq!(set.total_voting_power().value(), 1 + 2 + 10 + 4 + 5);
+            assert_eq!(set.hash(), expected.hash());
+        }
+
+        #[test]
+        fn test_try_extend_overflow_leaves_set_unchanged() {
+            let mut set = Set::without_proposer(vec![make_validator(
+                vec![1; 32],
+                Set::MAX_TOTAL_VOTING_POWER,
+            )]);
+            let original = set.clone();
+
+            let err = set
+                .try_extend([make_validator(vec![2; 32], 1)])
+                .unwrap_err();
+            assert!(
+                matches!(err.detail(), ErrorDetail::TotalVotingPowerOverflow(_)),
+                "{err}"
+            );
+            assert_eq!(set, original);
+        }
+
+        #[test]
+        fn test_total_voting_power_overflow() {
+            // The sum of those powers exceeds u64::MAX
+            let validators = vec![
+                make_validator(vec![1; 32], 6_148_914_691_236_517_205),
+                make_validator(vec![2; 32], 6_148_914_691_236_517_205),
+                make_validator(vec![3; 32], 6_148_914_691_236_517_206),
+            ];
+
+            let err = Set::try_new(validators, None).unwrap_err();
+            assert!(
+                matches!(err.detail(), ErrorDetail::TotalVotingPowerOverflow(_)),
+                "{err}"
+            );
+        }
+
+        #[test]
+        fn test_total_voting_power_exceeds_limit() {
+            let validators = vec![
+                make_validator(vec![1; 32], Set::MAX_TOTAL_VOTING_POWER),
+                make_validator(vec![2; 32], 1),
+            ];
+
+            let err = Set::try_new(validators, None).unwrap_err();
+            assert!(
+                matches!(err.detail(), ErrorDetail::TotalVotingPowerOverflow(_)),
+                "{err}"
+            );
+        }
     }
 
     #[test]
@@ -721,4 +1453,88 @@ mod tests {
             "{err}"
         );
     }
+
+    #[test]
+    fn validator_info_deserialize_proposer_priority() {
+        const INFO: &str = r#"{
+            "address": "01F527D77D3FFCC4FCFF2DDC2952EEA5414F2A33",
+            "pub_key": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "OAaNq3DX/15fGJP2MI6bujt1GRpvjwrqIevChirJsbc="
+            },
+            "voting_power": "50",
+            "proposer_priority": "-150"
+        }"#;
+
+        let info = serde_json::from_str::<Info>(INFO).unwrap();
+        assert_eq!(info.proposer_priority.value(), -150);
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["proposer_priority"], "-150");
+        assert_eq!(serde_json::from_value::<Info>(json).unwrap(), info);
+    }
+
+    #[test]
+    fn validator_info_deserialize_missing_proposer_priority() {
+        const INFO: &str = r#"{
+            "address": "01F527D77D3FFCC4FCFF2DDC2952EEA5414F2A33",
+            "pub_key": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "OAaNq3DX/15fGJP2MI6bujt1GRpvjwrqIevChirJsbc="
+            },
+            "voting_power": "50"
+        }"#;
+
+        let info = serde_json::from_str::<Info>(INFO).unwrap();
+        assert_eq!(info.proposer_priority, ProposerPriority::default());
+    }
+
+    #[test]
+    fn proposer_priority_serde() {
+        for value in [i64::MIN, -1, 0, 1, i64::MAX] {
+            let priority = ProposerPriority::from(value);
+            let json = serde_json::to_string(&priority).unwrap();
+            assert_eq!(json, format!("\"{value}\""));
+            assert_eq!(
+                serde_json::from_str::<ProposerPriority>(&json).unwrap(),
+                priority
+            );
+        }
+
+        assert!(serde_json::from_str::<ProposerPriority>("\"abc\"").is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ValidatorsResponse(#[serde(with = "validators_response")] Set);
+
+    #[test]
+    fn validator_set_validators_response_round_trip() {
+        let json = include_str!("../tests/support/serialization/validator/validators.json");
+        let ValidatorsResponse(set) = serde_json::from_str(json).unwrap();
+        assert_eq!(set.validators().len(), 1);
+        assert_eq!(set.total_voting_power().value(), 10);
+
+        // The set serializes back to the fixture, save for the height which
+        // is not part of the set.
+        let mut expected: serde_json::Value = serde_json::from_str(json).unwrap();
+        expected.as_object_mut().unwrap().remove("block_height");
+        let serialized = serde_json::to_value(ValidatorsResponse(set.clone())).unwrap();
+        assert_eq!(serialized, expected);
+
+        let ValidatorsResponse(reloaded) = serde_json::from_value(serialized).unwrap();
+        assert_eq!(reloaded, set);
+    }
+
+    #[test]
+    fn validator_set_validators_response_incomplete_page() {
+        let json = include_str!("../tests/support/serialization/validator/validators.json");
+        let mut page: serde_json::Value = serde_json::from_str(json).unwrap();
+        page["total"] = "2".into();
+
+        let err = serde_json::from_value::<ValidatorsResponse>(page).unwrap_err();
+        assert!(
+            err.to_string().contains("incomplete validator set"),
+            "{err}"
+        );
+    }
 }
@@ -250,6 +250,22 @@ impl Vote {
         self.block_id.map(|b| b.hash)
     }
 
+    /// Performs basic validation of this vote, independently of any
+    /// validator set or signature.
+    ///
+    /// Checks that the block ID, if any, either has both a hash and a part
+    /// set header, or neither of them.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if let Some(block_id) = &self.block_id {
+            let has_hash = !block_id.hash.is_empty();
+            let has_parts = block_id.part_set_header != block::parts::Header::default();
+            if has_hash != has_parts {
+                return Err(Error::invalid_block_id_parts());
+            }
+        }
+        Ok(())
+    }
+
     /// Create signable bytes from Vote.
     pub fn to_signable_bytes<B>(
         &self,
@@ -270,6 +286,12 @@ impl Vote {
         Protobuf::<RawCanonicalVote>::encode_length_delimited_vec(canonical)
     }
 
+    /// Return the bytes validators sign for this vote on the given chain,
+    /// i.e. the length-prefixed protobuf encoding of the [`CanonicalVote`].
+    pub fn sign_bytes(&self, chain_id: &ChainId) -> Vec<u8> {
+        self.clone().into_signable_vec(chain_id.clone())
+    }
+
     /// Consensus state from this vote - This doesn't seem to be used anywhere.
     #[deprecated(
         since = "0.17.0",
@@ -391,3 +413,61 @@ impl FromStr for Type {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{block::parts::Header as PartSetHeader, error::ErrorDetail, hash::Algorithm};
+
+    fn hash(byte: u8) -> hash::Hash {
+        hash::Hash::from_bytes(Algorithm::Sha256, &[byte; 32]).unwrap()
+    }
+
+    fn vote_for(block_id: Option<block::Id>) -> Vote {
+        Vote {
+            vote_type: Type::Precommit,
+            height: block::Height::from(1_u32),
+            round: block::Round::default(),
+            block_id,
+            timestamp: None,
+            validator_address: account::Id::new([0; 20]),
+            validator_index: ValidatorIndex::try_from(0_u32).unwrap(),
+            signature: None,
+            extension: vec![],
+            extension_signature: None,
+        }
+    }
+
+    #[test]
+    fn validate_basic_accepts_consistent_block_ids() {
+        let full = block::Id {
+            hash: hash(1),
+            part_set_header: PartSetHeader::new(1, hash(2)).unwrap(),
+        };
+        assert!(vote_for(Some(full)).validate_basic().is_ok());
+        assert!(vote_for(Some(block::Id::default()))
+            .validate_basic()
+            .is_ok());
+        assert!(vote_for(None).validate_basic().is_ok());
+    }
+
+    #[test]
+    fn validate_basic_rejects_hash_without_parts() {
+        let block_id = block::Id {
+            hash: hash(1),
+            part_set_header: PartSetHeader::default(),
+        };
+        let err = vote_for(Some(block_id)).validate_basic().unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::InvalidBlockIdParts(_)));
+    }
+
+    #[test]
+    fn validate_basic_rejects_parts_without_hash() {
+        let block_id = block::Id {
+            hash: hash::Hash::None,
+            part_set_header: PartSetHeader::new(1, hash(2)).unwrap(),
+        };
+        let err = vote_for(Some(block_id)).validate_basic().unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::InvalidBlockIdParts(_)));
+    }
+}
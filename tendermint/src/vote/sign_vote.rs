@@ -198,6 +198,97 @@ mod tests {
         assert_eq!(got2, want);
     }
 
+    #[test]
+    fn test_vote_sign_bytes() {
+        let dt = datetime!(2017-12-25 03:00:01.234 UTC);
+        let vote = Vote {
+            vote_type: Type::Prevote,
+            height: Height::from(12345_u32),
+            round: Round::from(2_u16),
+            timestamp: Some(dt.try_into().unwrap()),
+            block_id: Some(BlockId {
+                hash: Hash::try_from(b"DEADBEEFDEADBEEFBAFBAFBAFBAFBAFA".to_vec()).unwrap(),
+                part_set_header: Header::new(
+                    1_000_000,
+                    Hash::try_from(b"0022446688AACCEE1133557799BBDDFF".to_vec()).unwrap(),
+                )
+                .unwrap(),
+            }),
+            validator_address: AccountId::from_str("A3B2CCDD7186F1685F21F2482AF4FB3446A84B35")
+                .unwrap(),
+            validator_index: ValidatorIndex::try_from(56789).unwrap(),
+            signature: None,
+            extension: vec![],
+            extension_signature: None,
+        };
+        let chain_id = ChainId::from_str("test_chain_id").unwrap();
+
+        // Same vector as in `test_vote_serialization`, generated by
+        // `types.VoteSignBytes` in Go.
+        let want = "7C0801113930000000000000190200000000000000224A0A2044454144424545\
+                    46444541444245454642414642414642414642414642414641122608C0843D12\
+                    2030303232343436363838414143434545313133333535373739394242444446\
+                    462A0B08B1D381D20510809DCA6F320D746573745F636861696E5F6964";
+        let got = vote.sign_bytes(&chain_id);
+        assert_eq!(
+            String::from_utf8(subtle_encoding::hex::encode_upper(&got)).unwrap(),
+            want
+        );
+        assert_eq!(got, vote.into_signable_vec(chain_id));
+    }
+
+    #[cfg(feature = "rust-crypto")]
+    #[test]
+    fn test_vote_sign_bytes_verify_fixture_signature() {
+        use crate::crypto::{default::signature::Verifier, signature::Verifier as _};
+
+        // Precommit for height 10 of `dockerchain`, taken from the CometBFT
+        // 0.38 kvstore fixtures of the RPC crate (`commit_at_height_10.json`
+        // and `genesis.json`).
+        let vote = Vote {
+            vote_type: Type::Precommit,
+            height: Height::from(10_u32),
+            round: Round::from(0_u16),
+            timestamp: Some("2023-05-17T14:12:53.605374524Z".parse().unwrap()),
+            block_id: Some(BlockId {
+                hash: Hash::from_str(
+                    "00ECDAC463C201ECD4BDBBAAE4A53A4C80291D4051FD69ED97F6420CE1388BFE",
+                )
+                .unwrap(),
+                part_set_header: Header::new(
+                    1,
+                    Hash::from_str(
+                        "FF0A320E696FD233DD4D3CC7CD82FF90F54B8FDBC9C700D9375C95A02782B062",
+                    )
+                    .unwrap(),
+                )
+                .unwrap(),
+            }),
+            validator_address: AccountId::from_str("2DD9F44FD9067555C322243C3C913BA7B51D2BE0")
+                .unwrap(),
+            validator_index: ValidatorIndex::try_from(0).unwrap(),
+            signature: None,
+            extension: vec![],
+            extension_signature: None,
+        };
+        let pub_key = crate::PublicKey::from_raw_ed25519(
+            &subtle_encoding::base64::decode("bNNlGls5R25wC3Sd8720F/3+7IZBhXcD22MNFtPk/v0=")
+                .unwrap(),
+        )
+        .unwrap();
+        let signature = Signature::new(
+            subtle_encoding::base64::decode(
+                "5y0Kas3bSrgVYG/QKwWovMpTBfavZfy/A8DXkQHzFHVMjOcVk2TK6xhYQasfiodordg1bjDf7NDwNi/YdilaAw==",
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+
+        let sign_bytes = vote.sign_bytes(&ChainId::from_str("dockerchain").unwrap());
+        Verifier::verify(pub_key, &sign_bytes, &signature).unwrap();
+    }
+
     #[test]
     // Test vote encoding with a malformed block_id (no hash) which is considered nil in Go.
     fn test_vote_encoding_with_empty_block_id() {
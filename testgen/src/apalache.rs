@@ -1,18 +1,85 @@
-use std::{fmt::Write as _, io};
+use std::{collections::BTreeMap, fmt::Write as _, fs, io, path::Path};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use simple_error::SimpleError;
 
 use crate::{command::*, tester::TestEnv};
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ApalacheTestBatch {
     pub description: String,
     pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub length: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout: Option<u64>,
     pub tests: Vec<String>,
 }
 
+impl ApalacheTestBatch {
+    /// Constructs an empty batch of tests for the given model file.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            description: String::new(),
+            model: model.into(),
+            length: None,
+            timeout: None,
+            tests: Vec::new(),
+        }
+    }
+
+    /// Set the description of this batch.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Add the given tests, i.e. names of TLA+ operators, to this batch.
+    pub fn with_tests(mut self, tests: &[&str]) -> Self {
+        self.tests.extend(tests.iter().map(ToString::to_string));
+        self
+    }
+
+    /// Set the length bound of the Apalache runs.
+    pub fn length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Set the timeout of the Apalache runs, in seconds.
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Checks that the model file of this batch exists in the given directory.
+    pub fn validate(&self, dir: impl AsRef<Path>) -> Result<(), SimpleError> {
+        let path = dir.as_ref().join(&self.model);
+        if path.is_file() {
+            Ok(())
+        } else {
+            Err(SimpleError::new(format!(
+                "model file {} does not exist",
+                path.display()
+            )))
+        }
+    }
+
+    /// Returns the individual test cases of this batch.
+    pub fn test_cases(&self) -> Vec<ApalacheTestCase> {
+        self.tests
+            .iter()
+            .map(|test| ApalacheTestCase {
+                model: self.model.clone(),
+                test: test.clone(),
+                length: self.length,
+                timeout: self.timeout,
+            })
+            .collect()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ApalacheTestCase {
     pub model: String,
@@ -21,6 +88,14 @@ pub struct ApalacheTestCase {
     pub timeout: Option<u64>,
 }
 
+impl ApalacheTestCase {
+    /// The name of the invariant checked by Apalache for this test, i.e. the
+    /// negation of the test assertion added to the model.
+    pub fn invariant(&self) -> String {
+        format!("{}Inv", self.test)
+    }
+}
+
 pub enum ApalacheRun {
     /// Apalache has found a counterexample
     Counterexample(CommandRun),
@@ -50,7 +125,7 @@ impl ApalacheRun {
 }
 
 pub fn run_apalache_test(dir: &str, test: ApalacheTestCase) -> io::Result<ApalacheRun> {
-    let inv = format!("{}Inv", test.test);
+    let inv = test.invariant();
 
     // Mutate the model: negate the test assertion to get the invariant to check
     let mutation_failed = || {
@@ -60,7 +135,12 @@ pub fn run_apalache_test(dir: &str, test: ApalacheTestCase) -> io::Result<Apalac
         )
     };
     let env = TestEnv::new(dir).ok_or_else(mutation_failed)?;
-    let model = env.read_file(&test.model).unwrap();
+    let model = env.read_file(&test.model).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("model file {} does not exist", test.model),
+        )
+    })?;
     let mut new_model = String::new();
     for line in model.lines() {
         if line.starts_with(&inv) {
@@ -124,3 +204,205 @@ pub fn run_apalache_test(dir: &str, test: ApalacheTestCase) -> io::Result<Apalac
         Err(e) => Err(e),
     }
 }
+
+/// The name Apalache gives to the declaration holding the constant initialization.
+const CONST_INIT: &str = "ConstInit";
+
+/// The name Apalache gives to the declaration violating the checked invariant.
+const INVARIANT_VIOLATION: &str = "InvariantViolation";
+
+/// A counterexample produced by Apalache, as found in `counterexample.json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counterexample {
+    /// The sequence of states leading to the invariant violation
+    pub states: Vec<CounterexampleState>,
+    /// The name of the violated invariant
+    pub invariant: String,
+    /// The (JSON-encoded) formula which holds in the last state and violates
+    /// the invariant
+    pub violation: Value,
+}
+
+/// A single state of an Apalache counterexample.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CounterexampleState {
+    /// The name of the state declaration, e.g. `State1`
+    pub name: String,
+    /// The (JSON-encoded) values of the model variables in this state
+    pub variables: BTreeMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct RawCounterexample {
+    declarations: Vec<RawDeclaration>,
+}
+
+#[derive(Deserialize)]
+struct RawDeclaration {
+    operator: String,
+    body: Value,
+}
+
+#[derive(Deserialize)]
+struct RawAssignment {
+    eq: String,
+    arg: Value,
+}
+
+impl CounterexampleState {
+    fn from_body(name: String, body: Value) -> Result<Self, SimpleError> {
+        let error = |e: String| SimpleError::new(format!("failed to parse state {name}: {e}"));
+        // A state is a conjunction of assignments; a single assignment is not wrapped
+        let assignments = match body {
+            Value::Object(mut obj) if obj.contains_key("and") => obj.remove("and").unwrap(),
+            other => Value::Array(vec![other]),
+        };
+        let assignments: Vec<RawAssignment> =
+            serde_json::from_value(assignments).map_err(|e| error(e.to_string()))?;
+        let variables = assignments.into_iter().map(|a| (a.eq, a.arg)).collect();
+        Ok(CounterexampleState { name, variables })
+    }
+}
+
+/// Parses the counterexample produced by Apalache in JSON format
+/// (`counterexample.json`) at the given path, for the given test.
+///
+/// Apalache inlines the definition of the violated invariant in the
+/// counterexample, so its name is taken from the test that produced the run.
+pub fn parse_counterexample(
+    path: impl AsRef<Path>,
+    test: &ApalacheTestCase,
+) -> Result<Counterexample, SimpleError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(|e| {
+        SimpleError::new(format!(
+            "failed to read counterexample {}: {e}",
+            path.display()
+        ))
+    })?;
+    let raw: RawCounterexample = serde_json::from_str(&content).map_err(|e| {
+        SimpleError::new(format!(
+            "failed to parse counterexample {}: {e}",
+            path.display()
+        ))
+    })?;
+
+    let mut states = Vec::new();
+    let mut violation = None;
+    for (i, decl) in raw.declarations.into_iter().enumerate() {
+        match decl.operator.as_str() {
+            INVARIANT_VIOLATION => violation = Some(decl.body),
+            // The counterexample opens with the initialization of the constants,
+            // trivially `TRUE` when the model does not declare any
+            CONST_INIT => {},
+            _ if i == 0 && decl.body == Value::Bool(true) => {},
+            _ => states.push(CounterexampleState::from_body(decl.operator, decl.body)?),
+        }
+    }
+    let violation = violation.ok_or_else(|| {
+        SimpleError::new(format!(
+            "counterexample {} does not contain an invariant violation",
+            path.display()
+        ))
+    })?;
+
+    Ok(Counterexample {
+        states,
+        invariant: test.invariant(),
+        violation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_case(model: &str, test: &str) -> ApalacheTestCase {
+        ApalacheTestCase {
+            model: model.to_string(),
+            test: test.to_string(),
+            length: None,
+            timeout: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_counterexample() {
+        // Counterexample to `TestHeaderFromFuture` of the light client model
+        // `MC4_4_faulty.tla`, see `MC4_4_faulty_TestHeaderFromFuture.tla` under
+        // `light-client/tests/support/model_based/single_step`
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/support/apalache/counterexample.json"
+        );
+        let test = test_case("MC4_4_faulty.tla", "TestHeaderFromFuture");
+        let ce = parse_counterexample(path, &test).unwrap();
+
+        let names: Vec<_> = ce.states.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["State2", "State3"]);
+        assert_eq!(ce.invariant, "TestHeaderFromFutureInv");
+        assert!(ce.violation.is_object());
+
+        let last = ce.states.last().unwrap();
+        assert_eq!(
+            last.variables.keys().collect::<Vec<_>>(),
+            vec![
+                "Faulty",
+                "blockchain",
+                "fetchedLightBlocks",
+                "history",
+                "latestVerified",
+                "lightBlockStatus",
+                "nextHeight",
+                "now",
+                "nprobes",
+                "prevCurrent",
+                "prevNow",
+                "prevVerdict",
+                "prevVerified",
+                "state",
+            ]
+        );
+        assert_eq!(last.variables["now"], serde_json::json!(1400));
+        assert_eq!(
+            last.variables["state"],
+            serde_json::json!({ "str": "finishedFailure" })
+        );
+    }
+
+    #[test]
+    fn test_parse_counterexample_missing_file() {
+        let test = test_case("MC4_4_faulty.tla", "TestHeaderFromFuture");
+        assert!(parse_counterexample("does-not-exist.json", &test).is_err());
+    }
+
+    #[test]
+    fn test_build_batch() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/support/apalache");
+        let batch = ApalacheTestBatch::new("Example.tla")
+            .description("Example tests")
+            .with_tests(&["TestSuccess", "TestFailure"])
+            .length(10)
+            .timeout(60);
+        batch.validate(dir).unwrap();
+
+        let fixture = fs::read_to_string(format!("{dir}/batch.json")).unwrap();
+        assert_eq!(
+            serde_json::to_value(&batch).unwrap(),
+            serde_json::from_str::<Value>(&fixture).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<ApalacheTestBatch>(&fixture).unwrap(),
+            batch
+        );
+
+        let tests: Vec<_> = batch.test_cases().into_iter().map(|tc| tc.test).collect();
+        assert_eq!(tests, vec!["TestSuccess", "TestFailure"]);
+    }
+
+    #[test]
+    fn test_validate_batch_missing_model() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/support/apalache");
+        assert!(ApalacheTestBatch::new("Missing.tla").validate(dir).is_err());
+    }
+}
@@ -125,7 +125,13 @@ impl Generator<block::Commit> for Commit {
         let all_vals = sort_validators(&all_vals);
 
         let vote_to_sig = |v: &Vote| -> Result<block::CommitSig, SimpleError> {
-            let vote = v.generate()?;
+            // Most votes are for the header of the commit, which was already generated
+            let vote = match (&v.validator, &v.header) {
+                (Some(validator), Some(vote_header)) if vote_header == header => {
+                    v.generate_for(validator, header, &block_header)?
+                },
+                _ => v.generate()?,
+            };
             if vote.block_id.is_none() {
                 Ok(block::CommitSig::BlockIdFlagNil {
                     validator_address: vote.validator_address,
@@ -4,12 +4,18 @@ use std::str::FromStr;
 use gumdrop::Options;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use simple_error::*;
-use tendermint::{block, chain, validator, AppHash, Hash, Time};
+use tendermint::{
+    block::{self, signed_header::SignedHeader},
+    chain, validator, AppHash, Hash, Time,
+};
 use time::OffsetDateTime;
 
-use crate::{helpers::*, validator::generate_validators, Generator, Validator};
+use crate::{
+    helpers::*, light_block::generate_signed_header, validator::generate_validators, Commit,
+    Generator, Validator,
+};
 
-#[derive(Debug, Options, Serialize, Deserialize, Clone)]
+#[derive(Debug, Options, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Header {
     #[options(
         help = "validators (required), encoded as array of 'validator' parameters",
@@ -73,14 +79,14 @@ mod app_hash_serde {
     where
         D: Deserializer<'de>,
     {
-        tendermint::serializers::apphash::deserialize(deserializer).map(Some)
+        tendermint::serializers::apphash_hex::deserialize(deserializer).map(Some)
     }
 
     pub fn serialize<S>(value: &Option<AppHash>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        tendermint::serializers::apphash::serialize(value.as_ref().unwrap(), serializer)
+        tendermint::serializers::apphash_hex::serialize(value.as_ref().unwrap(), serializer)
     }
 }
 
@@ -133,6 +139,13 @@ impl Header {
             app_hash: self.app_hash.clone(),
         }
     }
+
+    /// Generate a signed header, with a commit for round 1 signed by all the
+    /// validators of this header.
+    pub fn signed_header(&self) -> Result<SignedHeader, SimpleError> {
+        let commit = Commit::new(self.clone(), 1);
+        generate_signed_header(self, &commit)
+    }
 }
 
 impl std::str::FromStr for Header {
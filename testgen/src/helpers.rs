@@ -2,7 +2,7 @@
 
 use std::io::{self, Read};
 
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use simple_error::*;
 use tendermint::{chain, public_key, signature::Signature, vote, Time};
 
@@ -31,6 +31,20 @@ pub fn parse_as<T: DeserializeOwned>(input: &str) -> Result<T, SimpleError> {
     }
 }
 
+/// Serializes the given value (e.g. a light block) into canonical JSON:
+/// pretty-printed, with object keys in lexicographic order and numbers in
+/// their shortest form, so that equal values always produce identical output.
+/// This allows JSON fixtures to be regenerated and diffed reliably.
+pub fn canonical_json<T: Serialize>(value: &T) -> Result<String, SimpleError> {
+    // Without the `preserve_order` feature, `serde_json::Value` keeps object
+    // keys sorted.
+    let value = serde_json::to_value(value).map_err(|e| SimpleError::new(e.to_string()))?;
+    let mut json =
+        serde_json::to_string_pretty(&value).map_err(|e| SimpleError::new(e.to_string()))?;
+    json.push('\n');
+    Ok(json)
+}
+
 pub fn read_stdin() -> Result<String, SimpleError> {
     let mut buffer = String::new();
     match io::stdin().read_to_string(&mut buffer) {
@@ -57,3 +71,34 @@ pub fn get_time(abs: u64) -> Result<Time, SimpleError> {
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{light_block::TmLightBlock, Generator, LightBlock};
+
+    #[test]
+    fn test_canonical_json_is_idempotent() {
+        let light_block = LightBlock::new_default(3).generate().unwrap();
+        let json = canonical_json(&light_block).unwrap();
+
+        let parsed: TmLightBlock = parse_as(&json).unwrap();
+        assert_eq!(parsed, light_block);
+        assert_eq!(canonical_json(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_canonical_json_normalizes_input() {
+        let a: serde_json::Value =
+            parse_as(r#"{"b": [1.50, 2E0], "a": {"y": "1", "x": null}}"#).unwrap();
+        let b: serde_json::Value =
+            parse_as(r#"{"a": {"x": null, "y": "1"}, "b": [1.5, 2.0]}"#).unwrap();
+
+        let json = canonical_json(&a).unwrap();
+        assert_eq!(json, canonical_json(&b).unwrap());
+        assert_eq!(
+            json,
+            "{\n  \"a\": {\n    \"x\": null,\n    \"y\": \"1\"\n  },\n  \"b\": [\n    1.5,\n    2.0\n  ]\n}\n"
+        );
+    }
+}
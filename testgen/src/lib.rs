@@ -35,7 +35,9 @@ pub use crate::time::Time;
 pub mod apalache;
 pub mod command;
 pub mod jsonatr;
+pub mod single_step;
 pub mod tester;
 
 pub use command::Command;
+pub use single_step::{generate_single_step, validate_fixture};
 pub use tester::{TestEnv, Tester};
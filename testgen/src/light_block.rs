@@ -2,8 +2,11 @@ use gumdrop::Options;
 use serde::{Deserialize, Serialize};
 use simple_error::*;
 use tendermint::{
-    block::signed_header::SignedHeader, node::Id as PeerId, validator,
-    validator::Set as ValidatorSet, Hash, Time,
+    block::{signed_header::SignedHeader, Height},
+    node::Id as PeerId,
+    validator,
+    validator::Set as ValidatorSet,
+    AppHash, Hash, Time,
 };
 
 use crate::{
@@ -251,6 +254,48 @@ pub fn generate_signed_header(
     Ok(SignedHeader::new(header, commit).unwrap())
 }
 
+/// Generates two conflicting light blocks at `divergence_height`, both extending
+/// the chain of the `trusted` light block.
+///
+/// The two blocks have the same parent, i.e. the chains agree up to
+/// `divergence_height - 1`, but their headers carry different app hashes and
+/// are thus signed by different commits.
+///
+/// Panics if `divergence_height` is not greater than the height of `trusted`.
+pub fn generate_fork(trusted: &LightBlock, divergence_height: Height) -> (LightBlock, LightBlock) {
+    let divergence_height = divergence_height.value();
+    assert!(
+        divergence_height > trusted.height(),
+        "divergence height must be greater than the trusted height"
+    );
+
+    let mut parent = trusted.clone();
+    while parent.height() + 1 < divergence_height {
+        parent = parent.next();
+    }
+
+    let block = parent.next();
+    let header = block.header.as_ref().expect("header is missing");
+
+    // Any app hash different from the original one makes for a conflicting header
+    let app_hash = header.app_hash.clone().unwrap_or_default();
+    let mut forked_app_hash: Vec<u8> = app_hash.as_bytes().iter().map(|b| !b).collect();
+    if forked_app_hash.is_empty() {
+        forked_app_hash = vec![0xFF; 32];
+    }
+    let forked_header = header
+        .clone()
+        .app_hash(AppHash::try_from(forked_app_hash).expect("invalid app hash"));
+
+    let forked_block = LightBlock {
+        header: Some(forked_header.clone()),
+        commit: Some(Commit::new(forked_header, 1)),
+        ..block.clone()
+    };
+
+    (block, forked_block)
+}
+
 pub fn default_peer_id() -> PeerId {
     "BADFADAD0BEFEEDC0C0ADEADBEEFC0FFEEFACADE".parse().unwrap()
 }
@@ -303,4 +348,25 @@ mod tests {
         assert_eq!(light_block_7.chain_id(), "test-chain");
         assert_ne!(light_block_6.generate(), light_block_7.generate());
     }
+
+    #[test]
+    fn test_generate_fork() {
+        let trusted = LightBlock::new_default(1);
+        let (block, forked_block) = generate_fork(&trusted, Height::try_from(3_u64).unwrap());
+
+        let block = block.generate().unwrap().signed_header;
+        let forked_block = forked_block.generate().unwrap().signed_header;
+
+        assert_eq!(block.header.height.value(), 3);
+        assert_eq!(forked_block.header.height.value(), 3);
+        assert_eq!(
+            block.header.last_block_id,
+            forked_block.header.last_block_id
+        );
+        assert!(block.header.last_block_id.is_some());
+
+        assert_ne!(block.header.app_hash, forked_block.header.app_hash);
+        assert_ne!(block.header.hash(), forked_block.header.hash());
+        assert_ne!(block.commit.signatures, forked_block.commit.signatures);
+    }
 }
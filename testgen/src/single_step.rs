@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use simple_error::*;
+use tendermint::{
+    block::signed_header::SignedHeader, node::Id as PeerId, validator, Duration, Time,
+};
+
+use crate::{
+    light_block::{LightBlock, TmLightBlock},
+    Generator,
+};
+
+/// The verdict the light client is expected to reach on a single-step input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    /// verified successfully
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /// outside of trusting period
+    #[serde(rename = "FAILED_TRUSTING_PERIOD")]
+    FailedTrustingPeriod,
+    /// block verification based on the header and commit structure failed
+    #[serde(rename = "INVALID")]
+    Invalid,
+    /// passed block verification, but the validator set is too different to verify it
+    #[serde(rename = "NOT_ENOUGH_TRUST")]
+    NotEnoughTrust,
+}
+
+/// The trusted state a single-step test case starts from.
+#[derive(Debug, Clone)]
+pub struct Initial {
+    pub light_block: LightBlock,
+    pub trusting_period: core::time::Duration,
+    pub now: Time,
+}
+
+/// A block to verify against the latest trusted one, checked at `now`.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub light_block: LightBlock,
+    pub now: Time,
+    pub verdict: Verdict,
+}
+
+#[derive(Serialize)]
+struct EncodedInitial {
+    signed_header: SignedHeader,
+    next_validator_set: validator::Set,
+    trusting_period: Duration,
+    now: Time,
+}
+
+// Mirrors the serialization of light-client's `LightBlock`, which renames
+// the validator sets compared to `TmLightBlock`.
+#[derive(Serialize)]
+struct EncodedLightBlock {
+    signed_header: SignedHeader,
+    validator_set: validator::Set,
+    next_validator_set: validator::Set,
+    provider: PeerId,
+}
+
+impl From<TmLightBlock> for EncodedLightBlock {
+    fn from(lb: TmLightBlock) -> Self {
+        Self {
+            signed_header: lb.signed_header,
+            validator_set: lb.validators,
+            next_validator_set: lb.next_validators,
+            provider: lb.provider,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct EncodedInput<'a> {
+    block: EncodedLightBlock,
+    testgen_block: &'a LightBlock,
+    now: Time,
+    verdict: Verdict,
+}
+
+#[derive(Serialize)]
+struct EncodedTestCase<'a> {
+    description: &'a str,
+    initial: EncodedInitial,
+    input: Vec<EncodedInput<'a>>,
+}
+
+/// Produces the JSON of a single-step light client test case, in the same
+/// format as the one obtained from Apalache counterexamples via jsonatr.
+///
+/// The trusted state is only updated by inputs expected to succeed.
+pub fn generate_single_step(
+    description: &str,
+    initial: &Initial,
+    inputs: &[Input],
+) -> Result<String, SimpleError> {
+    let trusted = initial.light_block.generate()?;
+    let initial = EncodedInitial {
+        signed_header: trusted.signed_header,
+        next_validator_set: trusted.next_validators,
+        trusting_period: initial.trusting_period.into(),
+        now: initial.now,
+    };
+    let input = inputs
+        .iter()
+        .map(|input| {
+            Ok(EncodedInput {
+                block: input.light_block.generate()?.into(),
+                testgen_block: &input.light_block,
+                now: input.now,
+                verdict: input.verdict,
+            })
+        })
+        .collect::<Result<Vec<_>, SimpleError>>()?;
+    let test_case = EncodedTestCase {
+        description,
+        initial,
+        input,
+    };
+    Ok(try_with!(
+        serde_json::to_string_pretty(&test_case),
+        "failed to serialize single-step test case"
+    ))
+}
+
+/// Checks the JSON of a single-step light client test case against the
+/// structure expected by the light client's model-based tests.
+///
+/// Rather than stopping at the first error, as deserialization would, all
+/// missing fields and fields of the wrong type are reported, each prefixed by
+/// its path in the fixture (e.g. `initial.trusting_period`).
+pub fn validate_fixture(json: &str) -> Result<(), Vec<String>> {
+    let value: Value = serde_json::from_str(json).map_err(|e| vec![e.to_string()])?;
+    let mut problems = Vec::new();
+    let mut checker = FixtureChecker {
+        problems: &mut problems,
+    };
+    checker.check_test_case(&value);
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
+struct FixtureChecker<'a> {
+    problems: &'a mut Vec<String>,
+}
+
+impl FixtureChecker<'_> {
+    fn check_test_case(&mut self, value: &Value) {
+        if !self.expect_object(value, "test case") {
+            return;
+        }
+        self.expect_field::<String>(value, "", "description");
+        if let Some(initial) = self.field(value, "", "initial") {
+            if self.expect_object(initial, "initial") {
+                self.expect_field::<SignedHeader>(initial, "initial", "signed_header");
+                self.expect_field::<validator::Set>(initial, "initial", "next_validator_set");
+                self.expect_field::<Duration>(initial, "initial", "trusting_period");
+                self.expect_field::<Time>(initial, "initial", "now");
+            }
+        }
+        match self.field(value, "", "input") {
+            Some(Value::Array(inputs)) => {
+                for (i, input) in inputs.iter().enumerate() {
+                    self.check_input(input, &format!("input[{i}]"));
+                }
+            },
+            Some(other) => self.wrong_type("input", "an array", other),
+            None => {},
+        }
+        if value.get("clock_drift").is_some() {
+            self.expect_field::<Duration>(value, "", "clock_drift");
+        }
+    }
+
+    fn check_input(&mut self, input: &Value, path: &str) {
+        if !self.expect_object(input, path) {
+            return;
+        }
+        if let Some(block) = self.field(input, path, "block") {
+            let block_path = format!("{path}.block");
+            if self.expect_object(block, &block_path) {
+                self.expect_field::<SignedHeader>(block, &block_path, "signed_header");
+                self.expect_field::<validator::Set>(block, &block_path, "validator_set");
+                self.expect_field::<validator::Set>(block, &block_path, "next_validator_set");
+                self.expect_field::<PeerId>(block, &block_path, "provider");
+            }
+        }
+        self.expect_field::<LightBlock>(input, path, "testgen_block");
+        self.expect_field::<Time>(input, path, "now");
+        self.expect_field::<Verdict>(input, path, "verdict");
+    }
+
+    fn field<'v>(&mut self, value: &'v Value, path: &str, name: &str) -> Option<&'v Value> {
+        let field = value.get(name);
+        if field.is_none() {
+            self.problems
+                .push(format!("missing field `{}`", join_path(path, name)));
+        }
+        field
+    }
+
+    fn expect_field<'v, T: Deserialize<'v>>(&mut self, value: &'v Value, path: &str, name: &str) {
+        if let Some(field) = self.field(value, path, name) {
+            if let Err(e) = T::deserialize(field) {
+                self.problems
+                    .push(format!("`{}`: {}", join_path(path, name), e));
+            }
+        }
+    }
+
+    fn expect_object(&mut self, value: &Value, path: &str) -> bool {
+        let is_object = value.is_object();
+        if !is_object {
+            self.wrong_type(path, "an object", value);
+        }
+        is_object
+    }
+
+    fn wrong_type(&mut self, path: &str, expected: &str, found: &Value) {
+        self.problems
+            .push(format!("`{path}`: expected {expected}, found {found}"));
+    }
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_single_step() {
+        let trusted = LightBlock::new_default(1);
+        let untrusted = trusted.next();
+        let initial = Initial {
+            light_block: trusted,
+            trusting_period: core::time::Duration::from_secs(60),
+            now: Time::from_unix_timestamp(1, 0).unwrap(),
+        };
+        let inputs = [Input {
+            light_block: untrusted,
+            now: Time::from_unix_timestamp(3, 0).unwrap(),
+            verdict: Verdict::Success,
+        }];
+
+        let json = generate_single_step("adjacent", &initial, &inputs).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["description"], "adjacent");
+        assert_eq!(value["initial"]["trusting_period"], "60000000000");
+        assert_eq!(value["initial"]["signed_header"]["header"]["height"], "1");
+        assert_eq!(
+            value["input"][0]["block"]["signed_header"]["header"]["height"],
+            "2"
+        );
+        assert_eq!(value["input"][0]["testgen_block"]["header"]["height"], 2);
+        assert_eq!(value["input"][0]["verdict"], "SUCCESS");
+    }
+
+    fn generated_fixture() -> Value {
+        let trusted = LightBlock::new_default(1);
+        let initial = Initial {
+            light_block: trusted.clone(),
+            trusting_period: core::time::Duration::from_secs(60),
+            now: Time::from_unix_timestamp(1, 0).unwrap(),
+        };
+        let inputs = [Input {
+            light_block: trusted.next(),
+            now: Time::from_unix_timestamp(3, 0).unwrap(),
+            verdict: Verdict::Success,
+        }];
+        let json = generate_single_step("adjacent", &initial, &inputs).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_validate_fixture_accepts_generated_fixture() {
+        let fixture = generated_fixture();
+        assert_eq!(validate_fixture(&fixture.to_string()), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_fixture_reports_missing_trusting_period() {
+        let mut fixture = generated_fixture();
+        fixture["initial"]
+            .as_object_mut()
+            .unwrap()
+            .remove("trusting_period");
+
+        let problems = validate_fixture(&fixture.to_string()).unwrap_err();
+        assert_eq!(
+            problems,
+            vec!["missing field `initial.trusting_period`".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_fixture_reports_all_problems() {
+        let mut fixture = generated_fixture();
+        fixture["description"] = Value::from(42);
+        fixture["clock_drift"] = Value::from("5000000000");
+        fixture["input"][0]["verdict"] = Value::from("MAYBE");
+        fixture["input"][0].as_object_mut().unwrap().remove("now");
+
+        let problems = validate_fixture(&fixture.to_string()).unwrap_err();
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems[0].starts_with("`description`: invalid type"));
+        assert_eq!(problems[1], "missing field `input[0].now`");
+        assert!(problems[2].starts_with("`input[0].verdict`: unknown variant"));
+    }
+}
@@ -184,7 +184,8 @@ pub struct Test {
 ///
 ///  After you have added all your test functions, you run Tester either on individual files
 ///  using run_for_file(), or for whole directories, using run_foreach_in_dir();
-///  the directories will be traversed recursively top-down.
+///  the directories will be traversed recursively top-down, unless disabled with recursive().
+///  Only `.json` files are run; entries whose names start with '_' or '.' are skipped.
 ///
 ///  The last step involves calling the finalize() function, which will produce the test report
 ///  and panic in case there was at least one failing test.
@@ -196,6 +197,7 @@ pub struct Tester {
     tests: Vec<Test>,
     batches: Vec<BatchFn>,
     results: std::collections::BTreeMap<String, Vec<(String, TestResult)>>,
+    recursive: bool,
 }
 
 impl TestResult {
@@ -224,9 +226,16 @@ impl Tester {
             tests: vec![],
             batches: vec![],
             results: Default::default(),
+            recursive: true,
         }
     }
 
+    /// Whether run_foreach_in_dir() should descend into subdirectories (the default).
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
     pub fn env(&self) -> Option<TestEnv> {
         TestEnv::new(&self.root_dir)
     }
@@ -438,10 +447,10 @@ impl Tester {
 
     pub fn run_foreach_in_dir(&mut self, dir: &str) {
         let full_dir = PathBuf::from(&self.root_dir).join(dir);
-        let starts_with_underscore = |entry: &DirEntry| {
+        let is_ignored = |entry: &DirEntry| {
             if let Some(last) = entry.path().iter().next_back() {
                 if let Some(last) = last.to_str() {
-                    if last.starts_with('_') {
+                    if last.starts_with('_') || last.starts_with('.') {
                         return true;
                     }
                 }
@@ -454,8 +463,8 @@ impl Tester {
                 Err(_) => self.read_error(full_dir),
                 Ok(paths) => {
                     paths.flatten().for_each(|entry| {
-                        // ignore path components starting with '_'
-                        if starts_with_underscore(&entry) {
+                        // ignore path components starting with '_', and hidden ones
+                        if is_ignored(&entry) {
                             return;
                         }
                         if let Ok(kind) = entry.file_type() {
@@ -465,7 +474,7 @@ impl Tester {
                                 if rel_path.ends_with(".json") {
                                     self.run_for_file(&rel_path);
                                 }
-                            } else if kind.is_dir() {
+                            } else if kind.is_dir() && self.recursive {
                                 self.run_foreach_in_dir(&rel_path);
                             }
                         }
@@ -539,3 +548,75 @@ impl Tester {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_fixtures() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let env = TestEnv::new(dir.path().to_str().unwrap()).unwrap();
+        env.write_file("top.json", "1").unwrap();
+        env.write_file("README.md", "not a test").unwrap();
+        fs::create_dir_all(env.full_path("a/b")).unwrap();
+        env.write_file("a/middle.json", "2").unwrap();
+        env.write_file("a/b/bottom.json", "3").unwrap();
+        fs::create_dir_all(env.full_path(".hidden")).unwrap();
+        env.write_file(".hidden/skipped.json", "4").unwrap();
+        fs::create_dir_all(env.full_path("_ignored")).unwrap();
+        env.write_file("_ignored/skipped.json", "5").unwrap();
+        dir
+    }
+
+    fn run(tester: Tester) -> Vec<String> {
+        let mut tester = tester;
+        tester.add_test("number", |_: u64| {});
+        tester.run_foreach_in_dir("");
+        assert!(tester.unreadable_tests().is_empty());
+        assert!(tester.unparseable_tests().is_empty());
+        let mut tests = tester.successful_tests("number");
+        tests.sort();
+        tests
+    }
+
+    #[test]
+    fn run_foreach_in_dir_recurses_into_subdirectories() {
+        let dir = nested_fixtures();
+        let tester = Tester::new("nested", dir.path().to_str().unwrap());
+        assert_eq!(
+            run(tester),
+            ["a/b/bottom.json", "a/middle.json", "top.json"]
+        );
+    }
+
+    #[test]
+    fn run_foreach_in_dir_without_recursion() {
+        let dir = nested_fixtures();
+        let tester = Tester::new("flat", dir.path().to_str().unwrap()).recursive(false);
+        assert_eq!(run(tester), ["top.json"]);
+    }
+
+    #[test]
+    fn all_failures_are_reported() {
+        let dir = nested_fixtures();
+        let mut tester = Tester::new("failing", dir.path().to_str().unwrap());
+        tester.add_test("small", |n: u64| assert!(n < 2, "{n} is too large"));
+        tester.run_foreach_in_dir("");
+
+        // A failing case does not stop the remaining ones from running
+        let mut failed: Vec<_> = tester
+            .failed_tests("small")
+            .into_iter()
+            .map(|(path, message, _)| (path, message))
+            .collect();
+        failed.sort();
+        assert_eq!(
+            failed,
+            [
+                ("a/b/bottom.json".to_string(), "3 is too large".to_string()),
+                ("a/middle.json".to_string(), "2 is too large".to_string()),
+            ]
+        );
+        assert_eq!(tester.successful_tests("small"), ["top.json"]);
+    }
+}
@@ -2,12 +2,64 @@ use gumdrop::Options;
 use serde::{Deserialize, Serialize};
 use simple_error::*;
 use tendermint::{
-    account, consensus::state::Ordering, private_key, public_key, public_key::PublicKey, validator,
-    vote,
+    account, consensus::state::Ordering, private_key, public_key, public_key::PublicKey,
+    signature::Signature, validator, vote,
 };
 
 use crate::{helpers::*, Generator};
 
+/// The type of the key pair of a validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyType {
+    Ed25519,
+    /// Requires the `secp256k1` feature
+    Secp256k1,
+}
+
+impl std::str::FromStr for KeyType {
+    type Err = SimpleError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ed25519" => Ok(KeyType::Ed25519),
+            "secp256k1" => Ok(KeyType::Secp256k1),
+            _ => bail!("unknown key type: {}", s),
+        }
+    }
+}
+
+/// The signing key of a validator companion, of the validator's key type.
+pub enum SigningKey {
+    Ed25519(ed25519_consensus::SigningKey),
+    #[cfg(feature = "secp256k1")]
+    Secp256k1(k256::ecdsa::SigningKey),
+}
+
+impl SigningKey {
+    /// Get the public key associated with this signing key.
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            SigningKey::Ed25519(key) => PublicKey::from(key.verification_key()),
+            #[cfg(feature = "secp256k1")]
+            SigningKey::Secp256k1(key) => PublicKey::Secp256k1(*key.verifying_key()),
+        }
+    }
+
+    /// Sign the given message.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        match self {
+            SigningKey::Ed25519(key) => key.sign(msg).into(),
+            #[cfg(feature = "secp256k1")]
+            SigningKey::Secp256k1(key) => {
+                use k256::ecdsa::signature::Signer;
+
+                let signature: k256::ecdsa::Signature = key.sign(msg);
+                signature.into()
+            },
+        }
+    }
+}
+
 #[derive(Debug, Options, Serialize, Deserialize, Clone)]
 pub struct Validator {
     #[options(help = "validator id (required; can be passed via STDIN)")]
@@ -19,6 +71,12 @@ pub struct Validator {
         meta = "PRIORITY"
     )]
     pub proposer_priority: Option<i64>,
+    #[options(
+        help = "key type of this validator: ed25519 or secp256k1 (default: ed25519)",
+        meta = "TYPE"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<KeyType>,
 }
 
 impl Validator {
@@ -27,15 +85,17 @@ impl Validator {
             id: Some(id.to_string()),
             voting_power: None,
             proposer_priority: None,
+            key_type: None,
         }
     }
     // Question: Why do we need this option since we're already initializing id with fn new()??
     set_option!(id, &str, Some(id.to_string()));
     set_option!(voting_power, u64);
     set_option!(proposer_priority, i64);
+    set_option!(key_type, KeyType);
 
-    /// Get private key for this validator companion.
-    pub fn get_private_key(&self) -> Result<private_key::Ed25519, SimpleError> {
+    /// Get the 32 bytes seed of the keys of this validator companion.
+    fn get_seed(&self) -> Result<Vec<u8>, SimpleError> {
         let id = match &self.id {
             None => bail!("validator identifier is missing"),
             Some(id) => id,
@@ -48,6 +108,34 @@ impl Validator {
             bail!("validator identifier is too long")
         }
         bytes.extend(vec![0u8; 32 - bytes.len()].iter());
+        Ok(bytes)
+    }
+
+    /// Get the signing key for this validator companion, of its key type.
+    pub fn get_signing_key(&self) -> Result<SigningKey, SimpleError> {
+        match self.key_type.unwrap_or(KeyType::Ed25519) {
+            KeyType::Ed25519 => {
+                let signing_key = self.get_private_key()?;
+                Ok(SigningKey::Ed25519(
+                    ed25519_consensus::SigningKey::try_from(signing_key).unwrap(),
+                ))
+            },
+            #[cfg(feature = "secp256k1")]
+            KeyType::Secp256k1 => {
+                let signing_key = require_with!(
+                    k256::ecdsa::SigningKey::from_slice(&self.get_seed()?).ok(),
+                    "failed to construct a secp256k1 key from validator identifier"
+                );
+                Ok(SigningKey::Secp256k1(signing_key))
+            },
+            #[cfg(not(feature = "secp256k1"))]
+            KeyType::Secp256k1 => bail!("secp256k1 validators require the `secp256k1` feature"),
+        }
+    }
+
+    /// Get the Ed25519 private key for this validator companion.
+    pub fn get_private_key(&self) -> Result<private_key::Ed25519, SimpleError> {
+        let bytes = self.get_seed()?;
         let signing_key = require_with!(
             private_key::Ed25519::try_from(&bytes[..]).ok(),
             "failed to construct a seed from validator identifier"
@@ -101,14 +189,15 @@ impl Generator<validator::Info> for Validator {
             id: self.id.or(default.id),
             voting_power: self.voting_power.or(default.voting_power),
             proposer_priority: self.proposer_priority.or(default.proposer_priority),
+            key_type: self.key_type.or(default.key_type),
         }
     }
 
     fn generate(&self) -> Result<validator::Info, SimpleError> {
-        let verification_key = self.get_private_key()?.verification_key();
+        let public_key = self.get_signing_key()?.public_key();
         let info = validator::Info {
-            address: account::Id::from(verification_key),
-            pub_key: PublicKey::from(verification_key),
+            address: account::Id::from(public_key),
+            pub_key: public_key,
             power: vote::Power::try_from(self.voting_power.unwrap_or(0)).unwrap(),
             name: None,
             proposer_priority: validator::ProposerPriority::from(
@@ -87,10 +87,24 @@ impl Generator<vote::Vote> for Vote {
             None => bail!("failed to generate vote: header is missing"),
             Some(h) => h,
         };
-        let signing_key = validator.get_private_key()?;
-        let signing_key = ed25519_consensus::SigningKey::try_from(signing_key).unwrap();
+        self.generate_for(validator, header, &header.generate()?)
+    }
+}
+
+impl Vote {
+    /// Generates a vote of `validator` for the already generated
+    /// `block_header`, which was generated from `header`.
+    ///
+    /// This saves generating the header once per vote when generating a
+    /// commit, which is costly for large validator sets.
+    pub(crate) fn generate_for(
+        &self,
+        validator: &Validator,
+        header: &Header,
+        block_header: &block::Header,
+    ) -> Result<vote::Vote, SimpleError> {
+        let signing_key = validator.get_signing_key()?;
         let block_validator = validator.generate()?;
-        let block_header = header.generate()?;
         let block_id = if self.nil.is_some() {
             None
         } else {
@@ -137,8 +151,8 @@ impl Generator<vote::Vote> for Vote {
             extension_signature: None,
         };
 
-        let sign_bytes = get_vote_sign_bytes(block_header.chain_id, &vote);
-        vote.signature = Some(signing_key.sign(sign_bytes.as_slice()).into());
+        let sign_bytes = get_vote_sign_bytes(block_header.chain_id.clone(), &vote);
+        vote.signature = Some(signing_key.sign(sign_bytes.as_slice()));
 
         Ok(vote)
     }
@@ -0,0 +1,12 @@
+------------------------------ MODULE Example ------------------------------
+VARIABLE state
+
+Init == state = "init"
+
+Next == state' = "done"
+
+TestSuccess == state = "done"
+
+TestFailure == state = "failed"
+
+=============================================================================
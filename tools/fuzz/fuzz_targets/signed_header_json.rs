@@ -0,0 +1,13 @@
+//! Deserializing arbitrary JSON into headers and commits must return an
+//! error rather than panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tendermint::block::{signed_header::SignedHeader, Commit, Header};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<Header>(data);
+    let _ = serde_json::from_slice::<Commit>(data);
+    let _ = serde_json::from_slice::<SignedHeader>(data);
+});
@@ -0,0 +1,16 @@
+//! Decoding arbitrary protobuf bytes into headers and signed headers must
+//! return an error rather than panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tendermint::block::{signed_header::SignedHeader, Header};
+use tendermint_proto::{
+    v0_38::types::{Header as RawHeader, SignedHeader as RawSignedHeader},
+    Protobuf,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = <Header as Protobuf<RawHeader>>::decode_vec(data);
+    let _ = <SignedHeader as Protobuf<RawSignedHeader>>::decode_vec(data);
+});
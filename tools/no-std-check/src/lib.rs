@@ -24,6 +24,26 @@ use sp_std;
 
 use core::panic::PanicInfo;
 
+/// Verifies `untrusted` against `trusted` with the production verifier,
+/// checking that the whole verification path, cryptography included, builds
+/// without `std`.
+#[cfg(feature = "verify")]
+pub fn verify_single(
+    untrusted: &tendermint_light_client_verifier::types::LightBlock,
+    trusted: &tendermint_light_client_verifier::types::LightBlock,
+    options: &tendermint_light_client_verifier::options::Options,
+    now: tendermint::Time,
+) -> tendermint_light_client_verifier::Verdict {
+    use tendermint_light_client_verifier::{ProdVerifier, Verifier};
+
+    ProdVerifier::default().verify_update_header(
+        untrusted.as_untrusted_state(),
+        trusted.as_trusted_state(),
+        options,
+        now,
+    )
+}
+
 /*
 
 This function definition checks for the compliance of no-std in
@@ -0,0 +1,45 @@
+//! Runs the `no_std` verification path on generated light blocks.
+//!
+//! Run with `cargo test --features verify`.
+
+#![cfg(feature = "verify")]
+
+use core::time::Duration;
+
+use tendermint::Time;
+use tendermint_light_client_verifier::{
+    options::Options,
+    types::{LightBlock, TrustThreshold},
+    Verdict,
+};
+use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+fn light_block(testgen: &TestgenLightBlock) -> LightBlock {
+    let tm_lb = testgen.generate().unwrap();
+    LightBlock {
+        signed_header: tm_lb.signed_header,
+        validators: tm_lb.validators,
+        next_validators: tm_lb.next_validators,
+        provider: tm_lb.provider,
+    }
+}
+
+#[test]
+fn verify_single_without_std() {
+    let testgen = TestgenLightBlock::new_default_with_time_and_chain_id(
+        "chain".to_owned(),
+        Time::from_unix_timestamp(1, 0).unwrap(),
+        1u64,
+    );
+    let trusted = light_block(&testgen);
+    let untrusted = light_block(&testgen.next());
+    let options = Options {
+        trust_threshold: TrustThreshold::ONE_THIRD,
+        trusting_period: Duration::from_secs(3600),
+        clock_drift: Duration::from_secs(1),
+    };
+    let now = Time::from_unix_timestamp(10, 0).unwrap();
+
+    let verdict = no_std_check::verify_single(&untrusted, &trusted, &options, now);
+    assert_eq!(verdict, Verdict::Success);
+}
@@ -67,6 +67,8 @@ const RENAME_VALIDATOR_POWER_QUOTED: &str =
     r#"#[serde(rename = "ValidatorPower", with = "crate::serializers::from_str")]"#;
 const RENAME_TIMESTAMP: &str = r#"#[serde(rename = "Timestamp")]"#;
 const RENAME_PARTS: &str = r#"#[serde(rename = "parts", alias = "part_set_header")]"#;
+const NULLABLE_ALIAS_PRECOMMITS: &str =
+    r#"#[serde(alias = "precommits", with = "crate::serializers::nullable")]"#;
 
 /// Custom type attributes applied on top of protobuf structs
 /// The first item in the tuple defines the message where the annotation should apply and
@@ -169,7 +171,7 @@ pub static CUSTOM_FIELD_ATTRIBUTES: &[(&str, &str)] = &[
     (".tendermint.types.Data.txs", NULLABLEVECARRAY),
     (".tendermint.types.EvidenceList.evidence", NULLABLE),
     (".tendermint.types.Commit.height", QUOTED),
-    (".tendermint.types.Commit.signatures", NULLABLE),
+    (".tendermint.types.Commit.signatures", NULLABLE_ALIAS_PRECOMMITS),
     (".tendermint.types.CommitSig.validator_address", HEXSTRING),
     (".tendermint.types.CommitSig.timestamp", OPTIONAL),
     (".tendermint.types.CommitSig.signature", BASE64STRING),